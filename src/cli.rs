@@ -1,4 +1,5 @@
 use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -13,6 +14,8 @@ pub struct Cli {
 #[derive(Subcommand, Debug)]
 pub enum Command {
   Create(App),
+  Sync(SyncOptions),
+  Generate(Generate),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -21,11 +24,260 @@ pub struct App {
   pub app_name: String,
   #[arg(short = 'c', long)]
   pub config: Option<std::path::PathBuf>,
+  /// Create the app under `apps/` of the enclosing deez workspace
+  #[arg(long)]
+  pub in_workspace: bool,
+  /// Workspace apps that depend on the created package (packages only)
+  #[arg(long, value_delimiter = ',')]
+  pub apps: Vec<String>,
+  #[command(flatten)]
+  pub options: TemplateOptions,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct SyncOptions {
+  #[arg(default_value = ".")]
+  pub app_path: std::path::PathBuf,
+  /// Write conflicting hunks to `.rej` files instead of conflict markers
+  #[arg(long)]
+  pub rej: bool,
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct Generate {
+  pub kind: GenerateKind,
+  pub name: Option<String>,
+  #[arg(short = 'p', long, default_value = ".")]
+  pub app_path: std::path::PathBuf,
+  /// Also generate a test file
+  #[arg(long)]
+  pub test: bool,
+  /// Also generate a Storybook story
+  #[arg(long)]
+  pub story: bool,
+  /// Also generate a CSS module
+  #[arg(long)]
+  pub css_module: bool,
 }
 
 #[derive(ValueEnum, Clone, Debug)]
+pub enum GenerateKind {
+  Component,
+  Page,
+  Layout,
+  ApiRoute,
+  Hook,
+  Module,
+  Controller,
+}
+
+// CLI names are the lowercased product name with spaces as hyphens, e.g.
+// `sveltekit` for SvelteKit but `react-router` for React Router.
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug)]
 pub enum AppType {
   React,
   Next,
   Astro,
+  Vue,
+  Nuxt,
+  #[value(name = "sveltekit")]
+  SvelteKit,
+  Solid,
+  #[value(name = "solidstart")]
+  SolidStart,
+  #[value(name = "react-router")]
+  ReactRouter,
+  Preact,
+  Lit,
+  Angular,
+  Node,
+  Nest,
+  Library,
+  Cli,
+  Extension,
+  Electron,
+  Tauri,
+  Wasm,
+  Expo,
+  Starlight,
+  #[value(name = "vitepress")]
+  VitePress,
+  Workspace,
+  Package,
+}
+
+#[derive(Args, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct TemplateOptions {
+  /// Router used by Next apps
+  #[arg(long, value_enum, default_value_t)]
+  pub router: NextRouter,
+  /// Put the app code under `src/` (Next only)
+  #[arg(long)]
+  pub src_dir: bool,
+  /// Use expo-router file-based routing (Expo only)
+  #[arg(long)]
+  pub expo_router: bool,
+  /// Language the app is written in
+  #[arg(long, value_enum, default_value_t)]
+  pub lang: Lang,
+  /// Adapter used by SvelteKit apps
+  #[arg(long, value_enum, default_value_t)]
+  pub adapter: SvelteAdapter,
+  /// Framework used by Node apps
+  #[arg(long, value_enum, default_value_t)]
+  pub server: ServerFramework,
+  /// Frontend used by Tauri apps
+  #[arg(long, value_enum, default_value_t)]
+  pub frontend: TauriFrontend,
+  /// Package manager the app's scripts and workspace use, taken from the create config
+  #[arg(skip)]
+  pub package_manager: PackageManager,
+  /// Task runner to generate pipelines for (workspaces only)
+  #[arg(long, value_enum, default_value_t)]
+  pub pipeline: Pipeline,
+  /// What a shared workspace package contains (packages only)
+  #[arg(long, value_enum, default_value_t)]
+  pub package_kind: PackageKind,
+}
+
+impl TemplateOptions {
+  /// The app type and options the frontend of a Tauri app is created with.
+  /// SvelteKit always gets the static adapter since Tauri serves prebuilt files.
+  pub fn tauri_frontend(&self) -> (AppType, TemplateOptions) {
+    let app_type = match self.frontend {
+      TauriFrontend::React => AppType::React,
+      TauriFrontend::Vue => AppType::Vue,
+      TauriFrontend::Svelte => AppType::SvelteKit,
+    };
+    let options = TemplateOptions {
+      adapter: SvelteAdapter::Static,
+      ..self.clone()
+    };
+
+    (app_type, options)
+  }
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum NextRouter {
+  #[default]
+  Pages,
+  App,
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Lang {
+  #[default]
+  Ts,
+  Js,
+}
+
+impl Lang {
+  pub fn script_ext(&self) -> &'static str {
+    match self {
+      Lang::Ts => "ts",
+      Lang::Js => "js",
+    }
+  }
+
+  pub fn jsx_ext(&self) -> &'static str {
+    match self {
+      Lang::Ts => "tsx",
+      Lang::Js => "jsx",
+    }
+  }
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SvelteAdapter {
+  #[default]
+  Auto,
+  Node,
+  Static,
+}
+
+impl SvelteAdapter {
+  pub fn package(&self) -> &'static str {
+    match self {
+      SvelteAdapter::Auto => "@sveltejs/adapter-auto",
+      SvelteAdapter::Node => "@sveltejs/adapter-node",
+      SvelteAdapter::Static => "@sveltejs/adapter-static",
+    }
+  }
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ServerFramework {
+  #[default]
+  Express,
+  Fastify,
+  Hono,
+}
+
+impl ServerFramework {
+  pub fn packages(&self) -> &'static [&'static str] {
+    match self {
+      ServerFramework::Express => &["express"],
+      ServerFramework::Fastify => &["fastify"],
+      ServerFramework::Hono => &["hono", "@hono/node-server"],
+    }
+  }
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TauriFrontend {
+  #[default]
+  React,
+  Vue,
+  Svelte,
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PackageManager {
+  #[default]
+  Pnpm,
+  Npm,
+  Yarn,
+}
+
+impl PackageManager {
+  pub fn command(&self) -> &'static str {
+    match self {
+      PackageManager::Pnpm => "pnpm",
+      PackageManager::Npm => "npm",
+      PackageManager::Yarn => "yarn",
+    }
+  }
+
+  /// The command that runs a package.json script, e.g. `pnpm run build`.
+  pub fn run(&self, script: &str) -> String {
+    format!("{} run {script}", self.command())
+  }
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Pipeline {
+  #[default]
+  None,
+  Turbo,
+  Nx,
+}
+
+impl Pipeline {
+  pub fn packages(&self) -> &'static [&'static str] {
+    match self {
+      Pipeline::None => &[],
+      Pipeline::Turbo => &["turbo"],
+      Pipeline::Nx => &["nx"],
+    }
+  }
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PackageKind {
+  #[default]
+  React,
+  Tsconfig,
+  Eslint,
 }
@@ -1,2 +1,6 @@
 pub mod create_app;
+pub mod generate;
+pub mod sync_app;
 pub use create_app::create_app;
+pub use generate::generate;
+pub use sync_app::sync_app;
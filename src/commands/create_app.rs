@@ -1,60 +1,296 @@
 mod apps;
 mod integrations;
+mod workspace;
 
-use std::io::{Read, Write};
+use std::io::Read;
 use std::str::FromStr;
 
-use self::apps::AppWithPath;
-use crate::cli::{App, AppType};
-use crate::configs::CreateAppConfig;
+pub use self::apps::AppWithPath;
+pub use self::workspace::{find_workspace, link_tsconfig};
+use crate::cli::{App, AppType, Lang, PackageKind, ServerFramework, TemplateOptions};
+use crate::configs::{AppManifest, CreateAppConfig};
 
 const DEEZ_CREATE_APP_CONFIG: &str = "deez_create_app_config";
 
 pub async fn create_app(app: App) -> anyhow::Result<()> {
+  use self::workspace::{
+    add_to_pipeline, check_link_targets, configured_package_manager, link_package,
+    write_dependencies,
+  };
+  use crate::utils::to_kebab_case;
+
   let App {
     app_name,
     app_type,
     config,
+    in_workspace,
+    apps,
+    mut options,
   } = app;
 
-  let app_path = create_app_dir(&app_name)?;
-  let packages = determine_packages(&app_type);
-  let dev_packages = determine_dev_packages(&app_type);
+  let workspace = if in_workspace {
+    if matches!(app_type, AppType::Workspace) {
+      anyhow::bail!("Workspaces can't be nested");
+    }
+    Some(find_workspace(&std::env::current_dir()?)?)
+  } else if matches!(app_type, AppType::Package) {
+    anyhow::bail!("Packages can only be created inside a workspace, pass --in-workspace");
+  } else {
+    None
+  };
+  if !apps.is_empty() && !matches!(app_type, AppType::Package) {
+    anyhow::bail!("--apps only applies to packages");
+  }
+  if let (Lang::Js, Some(name)) = (&options.lang, typescript_only(&app_type)) {
+    anyhow::bail!("{name} can only be created with TypeScript");
+  }
+  let packages = determine_packages(&app_type, &options);
+  let mut dev_packages = determine_dev_packages(&app_type, &options);
   handle_config(
-    &app_path,
     &packages,
     &dev_packages.clone().unwrap_or_default(),
     &config,
   )?;
+  options.package_manager = configured_package_manager()?;
+  if let Some(workspace) = &workspace {
+    if options.package_manager != workspace.package_manager {
+      anyhow::bail!(
+        "The workspace uses {:?}, set the same package_manager in the config",
+        workspace.package_manager
+      );
+    }
+    check_link_targets(workspace, &options.package_kind, &apps)?;
+  }
+  let parent_path = workspace.as_ref().map(|workspace| match app_type {
+    AppType::Package => workspace.path.join("packages"),
+    _ => workspace.path.join("apps"),
+  });
+  let app_path = create_app_dir(&app_name, parent_path.as_deref())?;
+  // Shared packages are scoped to the workspace, e.g. `@acme/ui`.
+  let app_name = match &workspace {
+    Some(workspace) if matches!(app_type, AppType::Package) => {
+      format!("@{}/{app_name}", to_kebab_case(&workspace.name))
+    }
+    _ => app_name,
+  };
   let app_with_path = AppWithPath {
     app_path: app_path.clone(),
-    app_name,
+    app_name: app_name.clone(),
     app_type: app_type.clone(),
+    options: options.clone(),
   };
   create_app_structure(app_with_path)?;
-  handle_integrations(app_path.clone(), &app_type)?;
-  handle_packages(packages, dev_packages, app_path).await?;
+  // Linked before the snapshot, so `deez sync` doesn't see it as a local edit.
+  if workspace.is_some() {
+    link_tsconfig(&app_path)?;
+  }
+  store_manifest(
+    &app_path,
+    app_type.clone(),
+    app_name.clone(),
+    options.clone(),
+  )?;
+  let integration_dev_packages =
+    handle_integrations(app_path.clone(), &app_type, &options, workspace.is_none())?;
+  if let Some(workspace) = &workspace {
+    if matches!(app_type, AppType::Package) {
+      link_package(
+        workspace,
+        &app_path,
+        &app_name,
+        &options.package_kind,
+        &apps,
+      )?;
+    }
+    add_to_pipeline(workspace, &app_path, &app_name, &app_type, &options)?;
+  }
+  if !integration_dev_packages.is_empty() {
+    dev_packages
+      .get_or_insert_with(Vec::new)
+      .extend(integration_dev_packages);
+  }
+  // Apps in a workspace share the root's lockfile and node_modules, so one
+  // install there fetches their packages and links any workspace packages.
+  match &workspace {
+    Some(workspace) => {
+      write_dependencies(&app_path, &packages, &dev_packages.unwrap_or_default())?;
+      install_workspace(workspace)?;
+    }
+    None => handle_packages(packages, dev_packages, app_path).await?,
+  }
 
   Ok(())
 }
 
-fn create_app_dir(app_name: &str) -> anyhow::Result<std::path::PathBuf> {
-  let dir = format!("./{app_name}");
-  let dir = std::path::Path::new(dir.as_str());
+fn create_app_dir(
+  app_name: &str,
+  parent_path: Option<&std::path::Path>,
+) -> anyhow::Result<std::path::PathBuf> {
+  let dir = match parent_path {
+    Some(parent_path) => parent_path.join(app_name),
+    None => std::path::PathBuf::from(format!("./{app_name}")),
+  };
 
-  std::fs::create_dir_all(dir)?;
+  std::fs::create_dir_all(&dir)?;
   Ok(dir.canonicalize()?)
 }
 
-fn determine_packages(app_type: &AppType) -> Vec<&str> {
+/// Names the app types whose templates only come in TypeScript.
+fn typescript_only(app_type: &AppType) -> Option<&'static str> {
+  match app_type {
+    AppType::Angular => Some("Angular apps"),
+    AppType::Node => Some("Node apps"),
+    AppType::Nest => Some("NestJS apps"),
+    AppType::Library => Some("Libraries"),
+    AppType::Cli => Some("CLI tools"),
+    AppType::Wasm => Some("Rust + WASM apps"),
+    _ => None,
+  }
+}
+
+fn determine_packages(app_type: &AppType, options: &TemplateOptions) -> Vec<&'static str> {
   match app_type {
     AppType::React => vec!["react", "react-dom"],
     AppType::Next => vec!["react", "react-dom", "next"],
     AppType::Astro => vec!["astro"],
+    AppType::Starlight => vec!["astro", "@astrojs/starlight", "sharp"],
+    AppType::VitePress => vec![],
+    AppType::Workspace => vec![],
+    AppType::Package => match options.package_kind {
+      PackageKind::Eslint => vec!["@eslint/js", "globals", "typescript-eslint"],
+      PackageKind::React | PackageKind::Tsconfig => vec![],
+    },
+    AppType::Vue => vec!["vue"],
+    AppType::Nuxt => vec!["nuxt", "vue", "vue-router"],
+    AppType::SvelteKit => vec![],
+    AppType::Solid => vec!["solid-js"],
+    AppType::SolidStart => vec![
+      "solid-js",
+      "@solidjs/start",
+      "@solidjs/router",
+      "@solidjs/meta",
+      "vinxi",
+    ],
+    AppType::ReactRouter => vec![
+      "react",
+      "react-dom",
+      "react-router",
+      "@react-router/node",
+      "@react-router/serve",
+      "isbot",
+    ],
+    AppType::Preact => vec!["preact"],
+    AppType::Lit => vec!["lit"],
+    AppType::Angular => vec![
+      "@angular/common",
+      "@angular/compiler",
+      "@angular/core",
+      "@angular/forms",
+      "@angular/platform-browser",
+      "@angular/router",
+      "rxjs",
+      "tslib",
+      "zone.js",
+    ],
+    AppType::Node => [options.server.packages(), &["dotenv"]].concat(),
+    AppType::Nest => vec![
+      "@nestjs/common",
+      "@nestjs/core",
+      "@nestjs/platform-express",
+      "reflect-metadata",
+      "rxjs",
+    ],
+    AppType::Library => vec![],
+    AppType::Cli => vec!["commander"],
+    AppType::Extension => vec!["react", "react-dom"],
+    AppType::Electron => vec!["react", "react-dom"],
+    AppType::Tauri => {
+      let (frontend_type, frontend_options) = options.tauri_frontend();
+
+      [
+        determine_packages(&frontend_type, &frontend_options),
+        vec!["@tauri-apps/api"],
+      ]
+      .concat()
+    }
+    AppType::Wasm => vec![],
+    AppType::Expo if options.expo_router => vec![
+      "expo",
+      "expo-constants",
+      "expo-linking",
+      "expo-router",
+      "expo-status-bar",
+      "react",
+      "react-dom",
+      "react-native",
+      "react-native-safe-area-context",
+      "react-native-screens",
+      "react-native-web",
+    ],
+    AppType::Expo => vec![
+      "expo",
+      "expo-status-bar",
+      "react",
+      "react-dom",
+      "react-native",
+      "react-native-web",
+    ],
   }
 }
 
-fn determine_dev_packages(app_type: &AppType) -> Option<Vec<&str>> {
+fn determine_dev_packages(
+  app_type: &AppType,
+  options: &TemplateOptions,
+) -> Option<Vec<&'static str>> {
+  if options.lang == Lang::Js {
+    return match app_type {
+      AppType::React => Some(vec!["vite", "@vitejs/plugin-react"]),
+      AppType::Vue => Some(vec!["vite", "@vitejs/plugin-vue"]),
+      AppType::Solid => Some(vec!["vite", "vite-plugin-solid"]),
+      AppType::Preact => Some(vec!["vite", "@preact/preset-vite"]),
+      AppType::Lit => Some(vec!["vite"]),
+      AppType::Extension => Some(vec!["vite", "@vitejs/plugin-react", "archiver"]),
+      AppType::Tauri => determine_tauri_dev_packages(options),
+      AppType::Expo => Some(vec!["@babel/core"]),
+      AppType::VitePress => Some(vec!["vitepress"]),
+      AppType::Workspace => Some([&["typescript"], options.pipeline.packages()].concat()),
+      AppType::Package => match options.package_kind {
+        PackageKind::React => Some(vec!["react"]),
+        PackageKind::Tsconfig => None,
+        PackageKind::Eslint => Some(vec!["eslint"]),
+      },
+      AppType::Electron => Some(vec![
+        "vite",
+        "@vitejs/plugin-react",
+        "electron",
+        "electron-builder",
+        "concurrently",
+        "wait-on",
+        "cross-env",
+      ]),
+      AppType::ReactRouter => Some(vec!["vite", "@react-router/dev", "@react-router/fs-routes"]),
+      AppType::SvelteKit => Some(vec![
+        "vite",
+        "svelte",
+        "svelte-check",
+        "@sveltejs/kit",
+        "@sveltejs/vite-plugin-svelte",
+        options.adapter.package(),
+      ]),
+      AppType::Next
+      | AppType::Astro
+      | AppType::Starlight
+      | AppType::Nuxt
+      | AppType::SolidStart
+      | AppType::Angular
+      | AppType::Node
+      | AppType::Nest
+      | AppType::Library
+      | AppType::Cli
+      | AppType::Wasm => None,
+    };
+  }
+
   match app_type {
     AppType::React => Some(vec![
       "vite",
@@ -70,11 +306,112 @@ fn determine_dev_packages(app_type: &AppType) -> Option<Vec<&str>> {
       "@types/react-dom",
     ]),
     AppType::Astro => None,
+    AppType::Starlight => None,
+    AppType::VitePress => Some(vec!["vitepress"]),
+    AppType::Workspace => Some([&["typescript"], options.pipeline.packages()].concat()),
+    AppType::Package => match options.package_kind {
+      PackageKind::React => Some(vec!["typescript", "react", "@types/react"]),
+      PackageKind::Tsconfig => None,
+      PackageKind::Eslint => Some(vec!["eslint"]),
+    },
+    AppType::Vue => Some(vec!["vite", "typescript", "vue-tsc", "@vitejs/plugin-vue"]),
+    AppType::Nuxt => Some(vec!["typescript", "vue-tsc"]),
+    AppType::SvelteKit => Some(vec![
+      "vite",
+      "typescript",
+      "svelte",
+      "svelte-check",
+      "@sveltejs/kit",
+      "@sveltejs/vite-plugin-svelte",
+      options.adapter.package(),
+    ]),
+    AppType::Solid => Some(vec!["vite", "typescript", "vite-plugin-solid"]),
+    AppType::SolidStart => Some(vec!["typescript"]),
+    AppType::ReactRouter => Some(vec![
+      "vite",
+      "typescript",
+      "@react-router/dev",
+      "@react-router/fs-routes",
+      "@types/node",
+      "@types/react",
+      "@types/react-dom",
+    ]),
+    AppType::Preact => Some(vec!["vite", "typescript", "@preact/preset-vite"]),
+    AppType::Lit => Some(vec!["vite", "typescript"]),
+    AppType::Angular => Some(vec![
+      "typescript",
+      "@angular/cli",
+      "@angular/compiler-cli",
+      "@angular-devkit/build-angular",
+      "@types/jasmine",
+      "jasmine-core",
+      "karma",
+      "karma-chrome-launcher",
+      "karma-coverage",
+      "karma-jasmine",
+      "karma-jasmine-html-reporter",
+    ]),
+    AppType::Node => match options.server {
+      ServerFramework::Express => Some(vec!["typescript", "tsx", "@types/node", "@types/express"]),
+      _ => Some(vec!["typescript", "tsx", "@types/node"]),
+    },
+    AppType::Nest => Some(vec![
+      "typescript",
+      "@nestjs/cli",
+      "@nestjs/schematics",
+      "@nestjs/testing",
+      "@types/node",
+      "@types/express",
+      "@types/jest",
+      "jest",
+      "ts-jest",
+    ]),
+    AppType::Library => Some(vec!["typescript", "tsup", "vitest"]),
+    AppType::Cli => Some(vec!["typescript", "tsup", "@types/node"]),
+    AppType::Extension => Some(vec![
+      "vite",
+      "typescript",
+      "archiver",
+      "@types/chrome",
+      "@types/react",
+      "@types/react-dom",
+      "@vitejs/plugin-react",
+    ]),
+    AppType::Electron => Some(vec![
+      "vite",
+      "typescript",
+      "@types/node",
+      "@types/react",
+      "@types/react-dom",
+      "@vitejs/plugin-react",
+      "electron",
+      "electron-builder",
+      "concurrently",
+      "wait-on",
+      "cross-env",
+    ]),
+    AppType::Tauri => determine_tauri_dev_packages(options),
+    AppType::Wasm => Some(vec![
+      "vite",
+      "typescript",
+      "vite-plugin-wasm",
+      "vite-plugin-top-level-await",
+      "wasm-pack",
+    ]),
+    AppType::Expo => Some(vec!["@babel/core", "typescript", "@types/react"]),
   }
 }
 
+fn determine_tauri_dev_packages(options: &TemplateOptions) -> Option<Vec<&'static str>> {
+  let (frontend_type, frontend_options) = options.tauri_frontend();
+  let mut dev_packages =
+    determine_dev_packages(&frontend_type, &frontend_options).unwrap_or_default();
+
+  dev_packages.push("@tauri-apps/cli");
+  Some(dev_packages)
+}
+
 fn handle_config(
-  app_path: &std::path::Path,
   packages: &Vec<&str>,
   dev_packages: &Vec<&str>,
   config: &Option<std::path::PathBuf>,
@@ -89,25 +426,19 @@ fn handle_config(
 
     read_and_store_config(config_path)?;
   } else {
-    let app_path = app_path.to_str().unwrap();
-    let config_path = std::path::PathBuf::from(format!("{app_path}/.deez.toml"));
-    let mut config_file = std::fs::File::create(&config_path)?;
-
-    config_file.write_all(
+    // Edited in a temp file, so nothing is written before the app dir exists.
+    let content = edit::edit_with_builder(
       format!(
         r"app_name = 'DeezNuts'
 package_manager = 'pnpm'
 packages = {packages:?}
 dev_packages  = {dev_packages:?}
 integrations = []"
-      )
-      .as_bytes(),
+      ),
+      edit::Builder::new().prefix(".deez").suffix(".toml"),
     )?;
 
-    edit::edit_file(&config_path)?;
-
-    read_and_store_config(&config_path)?;
-    std::fs::remove_file(config_path)?;
+    store_config(&content)?;
   };
 
   Ok(())
@@ -117,40 +448,94 @@ fn read_and_store_config(config_file: &std::path::PathBuf) -> anyhow::Result<()>
   let mut content = String::new();
 
   std::fs::File::open(&config_file)?.read_to_string(&mut content)?;
-  let config = toml::from_str::<CreateAppConfig>(&content)?;
+  store_config(&content)
+}
+
+fn store_config(content: &str) -> anyhow::Result<()> {
+  let config = toml::from_str::<CreateAppConfig>(content)?;
 
   confy::store(DEEZ_CREATE_APP_CONFIG, None, &config)?;
 
   Ok(())
 }
 
-fn create_app_structure(app: AppWithPath) -> anyhow::Result<()> {
-  use self::apps::{create_astro_app, create_next_app, create_react_app};
+pub fn create_app_structure(app: AppWithPath) -> anyhow::Result<()> {
+  use self::apps::{
+    create_angular_app, create_astro_app, create_cli_app, create_electron_app, create_expo_app,
+    create_extension_app, create_library_app, create_lit_app, create_nest_app, create_next_app,
+    create_node_app, create_nuxt_app, create_package_app, create_preact_app, create_react_app,
+    create_react_router_app, create_solid_app, create_solidstart_app, create_starlight_app,
+    create_sveltekit_app, create_tauri_app, create_vitepress_app, create_vue_app, create_wasm_app,
+    create_workspace_app,
+  };
   match app.app_type {
     AppType::React => create_react_app(app)?,
     AppType::Next => create_next_app(app)?,
     AppType::Astro => create_astro_app(app)?,
+    AppType::Starlight => create_starlight_app(app)?,
+    AppType::VitePress => create_vitepress_app(app)?,
+    AppType::Vue => create_vue_app(app)?,
+    AppType::Nuxt => create_nuxt_app(app)?,
+    AppType::SvelteKit => create_sveltekit_app(app)?,
+    AppType::Solid => create_solid_app(app)?,
+    AppType::SolidStart => create_solidstart_app(app)?,
+    AppType::ReactRouter => create_react_router_app(app)?,
+    AppType::Preact => create_preact_app(app)?,
+    AppType::Lit => create_lit_app(app)?,
+    AppType::Angular => create_angular_app(app)?,
+    AppType::Node => create_node_app(app)?,
+    AppType::Nest => create_nest_app(app)?,
+    AppType::Library => create_library_app(app)?,
+    AppType::Cli => create_cli_app(app)?,
+    AppType::Extension => create_extension_app(app)?,
+    AppType::Electron => create_electron_app(app)?,
+    AppType::Tauri => create_tauri_app(app)?,
+    AppType::Wasm => create_wasm_app(app)?,
+    AppType::Expo => create_expo_app(app)?,
+    AppType::Workspace => create_workspace_app(app)?,
+    AppType::Package => create_package_app(app)?,
   };
 
   Ok(())
 }
 
-fn handle_integrations(app_path: std::path::PathBuf, app_type: &AppType) -> anyhow::Result<()> {
-  use self::integrations::{add_git, add_tailwind};
+fn store_manifest(
+  app_path: &std::path::Path,
+  app_type: AppType,
+  app_name: String,
+  options: TemplateOptions,
+) -> anyhow::Result<()> {
+  let mut manifest = AppManifest::new(app_type, app_name, options);
+
+  manifest.snapshot(app_path, app_path)?;
+  manifest.store(app_path)?;
+
+  Ok(())
+}
+
+fn handle_integrations(
+  app_path: std::path::PathBuf,
+  app_type: &AppType,
+  options: &TemplateOptions,
+  init_git: bool,
+) -> anyhow::Result<Vec<&'static str>> {
+  use self::integrations::{add_eslint, add_git, add_tailwind};
   use crate::configs::create_app_config::Integrations;
 
   let cfg: CreateAppConfig = confy::load(DEEZ_CREATE_APP_CONFIG, None)?;
+  let mut dev_packages = Vec::new();
 
   if let Some(integrations) = cfg.integrations {
     for i in integrations {
       match i {
-        Integrations::Git => add_git(&app_path, app_type)?,
-        Integrations::Tailwind => add_tailwind()?,
+        Integrations::Git => add_git(&app_path, app_type, init_git)?,
+        Integrations::Tailwind => dev_packages.extend(add_tailwind(&app_path, app_type, options)?),
+        Integrations::Eslint => dev_packages.extend(add_eslint(&app_path, app_type, options)?),
       }
     }
   }
 
-  Ok(())
+  Ok(dev_packages)
 }
 
 async fn handle_packages(
@@ -161,8 +546,10 @@ async fn handle_packages(
   let path = path.to_str().unwrap();
   let packages = packages.as_slice();
 
-  println!("Installing dependencies..");
-  install_packages(packages, path, false).await?;
+  if !packages.is_empty() {
+    println!("Installing dependencies..");
+    install_packages(packages, path, false).await?;
+  }
 
   if let Some(dev_packages) = dev_packages {
     println!("Installing dev dependencies..");
@@ -172,6 +559,28 @@ async fn handle_packages(
   Ok(())
 }
 
+/// Runs an install at the workspace root, which fetches the apps' packages and
+/// links workspace packages into the apps that depend on them.
+fn install_workspace(workspace: &workspace::Workspace) -> anyhow::Result<()> {
+  use anyhow::Context;
+
+  let command = workspace.package_manager.command();
+  let hint = format!("run `{command} install` in the workspace root");
+
+  println!("Installing workspace dependencies..");
+  let status = std::process::Command::new(command)
+    .arg("install")
+    .current_dir(&workspace.path)
+    .status()
+    .with_context(|| format!("Could not start {command}, {hint}"))?;
+
+  if !status.success() {
+    anyhow::bail!("Installing failed, {hint}");
+  }
+
+  Ok(())
+}
+
 async fn install_packages(
   packages: &[&str],
   path: &str,
@@ -182,22 +591,25 @@ async fn install_packages(
   let cfg: CreateAppConfig = confy::load(DEEZ_CREATE_APP_CONFIG, None)?;
   let manager = kayra::Manager::from_str(cfg.package_manager.as_str())?;
 
-  let mut child = {
-    if as_dev_dependency {
-      kayra::PackageManager::new(manager)
-        .dir(path)
-        .flags(&["-D"])
-        .install(packages)
-        .async_run()
-        .await?
-    } else {
-      kayra::PackageManager::new(manager)
-        .dir(path)
-        .install(packages)
-        .async_run()
-        .await?
-    }
-  };
+  let mut flags = Vec::new();
+
+  if as_dev_dependency {
+    flags.push("-D");
+  }
+  // pnpm refuses to add dependencies to a workspace root without `-w`.
+  if std::path::Path::new(path)
+    .join("pnpm-workspace.yaml")
+    .exists()
+  {
+    flags.push("-w");
+  }
+
+  let mut child = kayra::PackageManager::new(manager)
+    .dir(path)
+    .flags(&flags)
+    .install(packages)
+    .async_run()
+    .await?;
 
   let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
 
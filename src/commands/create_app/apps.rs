@@ -1,14 +1,59 @@
+pub mod create_angular_app;
 pub mod create_astro_app;
+pub mod create_cli_app;
+pub mod create_electron_app;
+pub mod create_expo_app;
+pub mod create_extension_app;
+pub mod create_library_app;
+pub mod create_lit_app;
+pub mod create_nest_app;
 pub mod create_next_app;
+pub mod create_node_app;
+pub mod create_nuxt_app;
+pub mod create_package_app;
+pub mod create_preact_app;
 pub mod create_react_app;
+pub mod create_react_router_app;
+pub mod create_solid_app;
+pub mod create_solidstart_app;
+pub mod create_starlight_app;
+pub mod create_sveltekit_app;
+pub mod create_tauri_app;
+pub mod create_vitepress_app;
+pub mod create_vue_app;
+pub mod create_wasm_app;
+pub mod create_workspace_app;
+pub use create_angular_app::create_angular_app;
 pub use create_astro_app::create_astro_app;
+pub use create_cli_app::create_cli_app;
+pub use create_electron_app::create_electron_app;
+pub use create_expo_app::create_expo_app;
+pub use create_extension_app::create_extension_app;
+pub use create_library_app::create_library_app;
+pub use create_lit_app::create_lit_app;
+pub use create_nest_app::create_nest_app;
 pub use create_next_app::create_next_app;
+pub use create_node_app::create_node_app;
+pub use create_nuxt_app::create_nuxt_app;
+pub use create_package_app::create_package_app;
+pub use create_preact_app::create_preact_app;
 pub use create_react_app::create_react_app;
+pub use create_react_router_app::create_react_router_app;
+pub use create_solid_app::create_solid_app;
+pub use create_solidstart_app::create_solidstart_app;
+pub use create_starlight_app::create_starlight_app;
+pub use create_sveltekit_app::create_sveltekit_app;
+pub use create_tauri_app::create_tauri_app;
+pub use create_vitepress_app::create_vitepress_app;
+pub use create_vue_app::create_vue_app;
+pub use create_wasm_app::create_wasm_app;
+pub use create_workspace_app::create_workspace_app;
 
-use crate::cli::AppType;
+use crate::cli::{AppType, TemplateOptions};
 
 pub struct AppWithPath {
   pub app_type: AppType,
   pub app_name: String,
   pub app_path: std::path::PathBuf,
+  pub options: TemplateOptions,
 }
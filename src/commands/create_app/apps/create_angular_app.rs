@@ -0,0 +1,293 @@
+use std::io::Write;
+
+use super::AppWithPath;
+
+pub fn create_angular_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name, app_path, ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path)?;
+  create_angular_json(&app_name, app_path)?;
+  create_tsconfig(app_path)?;
+  create_folders(&app_name, app_path)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "scripts": {{
+    "ng": "ng",
+    "dev": "ng serve",
+    "build": "ng build",
+    "watch": "ng build --watch --configuration development",
+    "test": "ng test"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_angular_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let angular_json_path = std::path::PathBuf::from(format!("{app_path}/angular.json"));
+  let mut angular_json_file = std::fs::File::create(angular_json_path)?;
+  let content = format!(
+    r#"{{
+  "$schema": "./node_modules/@angular/cli/lib/config/schema.json",
+  "version": 1,
+  "newProjectRoot": "projects",
+  "projects": {{
+    "{app_name}": {{
+      "projectType": "application",
+      "root": "",
+      "sourceRoot": "src",
+      "prefix": "app",
+      "architect": {{
+        "build": {{
+          "builder": "@angular-devkit/build-angular:application",
+          "options": {{
+            "outputPath": "dist/{app_name}",
+            "index": "src/index.html",
+            "browser": "src/main.ts",
+            "polyfills": ["zone.js"],
+            "tsConfig": "tsconfig.app.json",
+            "assets": [{{ "glob": "**/*", "input": "public" }}],
+            "styles": ["src/styles.css"],
+            "scripts": []
+          }},
+          "configurations": {{
+            "production": {{
+              "budgets": [
+                {{
+                  "type": "initial",
+                  "maximumWarning": "500kB",
+                  "maximumError": "1MB"
+                }},
+                {{
+                  "type": "anyComponentStyle",
+                  "maximumWarning": "4kB",
+                  "maximumError": "8kB"
+                }}
+              ],
+              "outputHashing": "all"
+            }},
+            "development": {{
+              "optimization": false,
+              "extractLicenses": false,
+              "sourceMap": true
+            }}
+          }},
+          "defaultConfiguration": "production"
+        }},
+        "serve": {{
+          "builder": "@angular-devkit/build-angular:dev-server",
+          "configurations": {{
+            "production": {{
+              "buildTarget": "{app_name}:build:production"
+            }},
+            "development": {{
+              "buildTarget": "{app_name}:build:development"
+            }}
+          }},
+          "defaultConfiguration": "development"
+        }},
+        "test": {{
+          "builder": "@angular-devkit/build-angular:karma",
+          "options": {{
+            "polyfills": ["zone.js", "zone.js/testing"],
+            "tsConfig": "tsconfig.spec.json",
+            "assets": [{{ "glob": "**/*", "input": "public" }}],
+            "styles": ["src/styles.css"],
+            "scripts": []
+          }}
+        }}
+      }}
+    }}
+  }}
+}}"#
+  );
+
+  angular_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compileOnSave": false,
+  "compilerOptions": {
+    "outDir": "./dist/out-tsc",
+    "strict": true,
+    "noImplicitOverride": true,
+    "noPropertyAccessFromIndexSignature": true,
+    "noImplicitReturns": true,
+    "noFallthroughCasesInSwitch": true,
+    "skipLibCheck": true,
+    "isolatedModules": true,
+    "esModuleInterop": true,
+    "experimentalDecorators": true,
+    "moduleResolution": "bundler",
+    "importHelpers": true,
+    "target": "ES2022",
+    "module": "ES2022"
+  },
+  "angularCompilerOptions": {
+    "enableI18nLegacyMessageIdFormat": false,
+    "strictInjectionParameters": true,
+    "strictInputAccessModifiers": true,
+    "strictTemplates": true
+  }
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  let tsconfig_app_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.app.json"));
+  let mut tsconfig_app_file = std::fs::File::create(tsconfig_app_path)?;
+  let content = r#"{
+  "extends": "./tsconfig.json",
+  "compilerOptions": {
+    "outDir": "./out-tsc/app",
+    "types": []
+  },
+  "files": ["src/main.ts"],
+  "include": ["src/**/*.d.ts"]
+}"#;
+
+  tsconfig_app_file.write_all(content.as_bytes())?;
+
+  let tsconfig_spec_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.spec.json"));
+  let mut tsconfig_spec_file = std::fs::File::create(tsconfig_spec_path)?;
+  let content = r#"{
+  "extends": "./tsconfig.json",
+  "compilerOptions": {
+    "outDir": "./out-tsc/spec",
+    "types": ["jasmine"]
+  },
+  "include": ["src/**/*.spec.ts", "src/**/*.d.ts"]
+}"#;
+
+  tsconfig_spec_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/app/components/"))?;
+
+  let indexhtml_path = std::path::PathBuf::from(format!("{app_path}/src/index.html"));
+  let mut indexhtml_file = std::fs::File::create(indexhtml_path)?;
+
+  let indexhtml_content = format!(
+    r#"<!doctype html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <title>{app_name}</title>
+    <base href="/" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+  </head>
+  <body>
+    <app-root></app-root>
+  </body>
+</html>"#
+  );
+
+  indexhtml_file.write_all(indexhtml_content.as_bytes())?;
+
+  std::fs::File::create(format!("{app_path}/src/styles.css"))?;
+
+  let maints_path = std::path::PathBuf::from(format!("{app_path}/src/main.ts"));
+  let mut maints_file = std::fs::File::create(maints_path)?;
+
+  let maints_content = r#"import { bootstrapApplication } from "@angular/platform-browser";
+import { AppComponent } from "./app/app.component";
+import { appConfig } from "./app/app.config";
+
+bootstrapApplication(AppComponent, appConfig).catch((err) => console.error(err));"#;
+
+  maints_file.write_all(maints_content.as_bytes())?;
+
+  let appconfig_path = std::path::PathBuf::from(format!("{app_path}/src/app/app.config.ts"));
+  let mut appconfig_file = std::fs::File::create(appconfig_path)?;
+
+  let appconfig_content = r#"import { ApplicationConfig, provideZoneChangeDetection } from "@angular/core";
+import { provideRouter } from "@angular/router";
+import { routes } from "./app.routes";
+
+export const appConfig: ApplicationConfig = {
+  providers: [provideZoneChangeDetection({ eventCoalescing: true }), provideRouter(routes)],
+};"#;
+
+  appconfig_file.write_all(appconfig_content.as_bytes())?;
+
+  let approutes_path = std::path::PathBuf::from(format!("{app_path}/src/app/app.routes.ts"));
+  let mut approutes_file = std::fs::File::create(approutes_path)?;
+
+  let approutes_content = r#"import { Routes } from "@angular/router";
+
+export const routes: Routes = [];"#;
+
+  approutes_file.write_all(approutes_content.as_bytes())?;
+
+  let appcomponent_path = std::path::PathBuf::from(format!("{app_path}/src/app/app.component.ts"));
+  let mut appcomponent_file = std::fs::File::create(appcomponent_path)?;
+
+  let appcomponent_content = format!(
+    r#"import {{ Component }} from "@angular/core";
+import {{ RouterOutlet }} from "@angular/router";
+
+@Component({{
+  selector: "app-root",
+  imports: [RouterOutlet],
+  template: `
+    <main>
+      <router-outlet />
+    </main>
+  `,
+}})
+export class AppComponent {{
+  title = "{app_name}";
+}}"#
+  );
+
+  appcomponent_file.write_all(appcomponent_content.as_bytes())?;
+
+  let appcomponent_spec_path =
+    std::path::PathBuf::from(format!("{app_path}/src/app/app.component.spec.ts"));
+  let mut appcomponent_spec_file = std::fs::File::create(appcomponent_spec_path)?;
+
+  let appcomponent_spec_content = r#"import { TestBed } from "@angular/core/testing";
+import { AppComponent } from "./app.component";
+
+describe("AppComponent", () => {
+  beforeEach(async () => {
+    await TestBed.configureTestingModule({
+      imports: [AppComponent],
+    }).compileComponents();
+  });
+
+  it("should create the app", () => {
+    const fixture = TestBed.createComponent(AppComponent);
+    expect(fixture.componentInstance).toBeTruthy();
+  });
+});"#;
+
+  appcomponent_spec_file.write_all(appcomponent_spec_content.as_bytes())?;
+
+  Ok(())
+}
@@ -1,18 +1,25 @@
 use std::io::Write;
 
 use super::AppWithPath;
+use crate::cli::Lang;
 
 pub fn create_astro_app(app: AppWithPath) -> anyhow::Result<()> {
   let AppWithPath {
-    app_name, app_path, ..
+    app_name,
+    app_path,
+    options,
+    ..
   } = app;
 
   let app_path = app_path.to_str().unwrap();
 
   create_package_json(&app_name, app_path)?;
-  create_tsconfig(app_path)?;
+  match options.lang {
+    Lang::Ts => create_tsconfig(app_path)?,
+    Lang::Js => create_jsconfig(app_path)?,
+  };
   create_astroconfig(app_path)?;
-  create_folders(&app_name, app_path)?;
+  create_folders(&app_name, app_path, &options.lang)?;
 
   Ok(())
 }
@@ -54,6 +61,18 @@ fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
   Ok(())
 }
 
+fn create_jsconfig(app_path: &str) -> anyhow::Result<()> {
+  let jsconfig_path = std::path::PathBuf::from(format!("{app_path}/jsconfig.json"));
+  let mut jsconfig_file = std::fs::File::create(jsconfig_path)?;
+  let content = r#"{
+  "extends": "astro/tsconfigs/base"
+}"#;
+
+  jsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
 fn create_astroconfig(app_path: &str) -> anyhow::Result<()> {
   let astroconfig_path = std::path::PathBuf::from(format!("{app_path}/astro.config.mjs"));
   let mut astroconfig_file = std::fs::File::create(astroconfig_path)?;
@@ -66,18 +85,20 @@ export default defineConfig({});"#;
   Ok(())
 }
 
-fn create_folders(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+fn create_folders(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
   std::fs::create_dir_all(format!("{app_path}/public/"))?;
   std::fs::create_dir_all(format!("{app_path}/src/pages/"))?;
   std::fs::create_dir_all(format!("{app_path}/src/components/"))?;
   std::fs::create_dir_all(format!("{app_path}/src/layouts/"))?;
 
-  let env_path = std::path::PathBuf::from(format!("{app_path}/src/env.d.ts"));
-  let mut env_file = std::fs::File::create(env_path)?;
+  if *lang == Lang::Ts {
+    let env_path = std::path::PathBuf::from(format!("{app_path}/src/env.d.ts"));
+    let mut env_file = std::fs::File::create(env_path)?;
 
-  let env_content = r#"/// <reference types="astro/client" />"#;
+    let env_content = r#"/// <reference types="astro/client" />"#;
 
-  env_file.write_all(env_content.as_bytes())?;
+    env_file.write_all(env_content.as_bytes())?;
+  }
 
   let indexastro_path = std::path::PathBuf::from(format!("{app_path}/src/pages/index.astro"));
   let mut indexastro_file = std::fs::File::create(indexastro_path)?;
@@ -0,0 +1,137 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::PackageManager;
+
+pub fn create_cli_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options.package_manager)?;
+  create_tsconfig(app_path)?;
+  create_tsup_config(app_path)?;
+  create_folders(&app_name, app_path)?;
+
+  Ok(())
+}
+
+fn create_package_json(
+  app_name: &str,
+  app_path: &str,
+  package_manager: &PackageManager,
+) -> anyhow::Result<()> {
+  let prepublish = ["typecheck", "build"]
+    .map(|script| package_manager.run(script))
+    .join(" && ");
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "version": "0.1.0",
+  "type": "module",
+  "bin": {{
+    "{app_name}": "./dist/index.js"
+  }},
+  "files": ["dist"],
+  "scripts": {{
+    "build": "tsup",
+    "dev": "tsup --watch",
+    "start": "node dist/index.js",
+    "typecheck": "tsc --noEmit",
+    "prepublishOnly": "{prepublish}"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "target": "ES2022",
+    "lib": ["ES2022"],
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "types": ["node"],
+    "isolatedModules": true,
+    "esModuleInterop": true,
+    "forceConsistentCasingInFileNames": true,
+    "noEmit": true,
+    "skipLibCheck": true,
+    "strict": true
+  },
+  "include": ["src"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsup_config(app_path: &str) -> anyhow::Result<()> {
+  let tsup_config_path = std::path::PathBuf::from(format!("{app_path}/tsup.config.ts"));
+  let mut tsup_config_file = std::fs::File::create(tsup_config_path)?;
+  let content = r#"import { defineConfig } from "tsup";
+
+export default defineConfig({
+  entry: ["src/index.ts"],
+  format: ["esm"],
+  target: "node18",
+  platform: "node",
+  clean: true,
+});"#;
+
+  tsup_config_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src/commands/"))?;
+
+  let indexts_path = std::path::PathBuf::from(format!("{app_path}/src/index.ts"));
+  let mut indexts_file = std::fs::File::create(indexts_path)?;
+
+  let indexts_content = format!(
+    r#"#!/usr/bin/env node
+import {{ Command }} from "commander";
+import {{ helloCommand }} from "./commands/hello";
+
+const program = new Command();
+
+program.name("{app_name}").version("0.1.0");
+program.addCommand(helloCommand);
+
+program.parse();"#
+  );
+
+  indexts_file.write_all(indexts_content.as_bytes())?;
+
+  let hellots_path = std::path::PathBuf::from(format!("{app_path}/src/commands/hello.ts"));
+  let mut hellots_file = std::fs::File::create(hellots_path)?;
+
+  let hellots_content = r#"import { Command } from "commander";
+
+export const helloCommand = new Command("hello")
+  .description("Print a greeting")
+  .argument("[name]", "who to greet", "world")
+  .action((name: string) => {
+    console.log(`Hello, ${name}!`);
+  });"#;
+
+  hellots_file.write_all(hellots_content.as_bytes())?;
+
+  Ok(())
+}
@@ -0,0 +1,246 @@
+use std::io::Write;
+
+use super::{create_react_app, AppWithPath};
+use crate::cli::{Lang, PackageManager};
+
+pub fn create_electron_app(app: AppWithPath) -> anyhow::Result<()> {
+  let app_name = app.app_name.clone();
+  let app_path = app.app_path.clone();
+  let lang = app.options.lang.clone();
+  let package_manager = app.options.package_manager.clone();
+
+  create_react_app(app)?;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &lang, &package_manager)?;
+  create_viteconfig(app_path, &lang)?;
+  if lang == Lang::Ts {
+    create_tsconfig(app_path)?;
+  }
+  create_builder_config(&app_name, app_path, &lang)?;
+  create_folders(app_path, &lang)?;
+
+  Ok(())
+}
+
+fn create_package_json(
+  app_name: &str,
+  app_path: &str,
+  lang: &Lang,
+  package_manager: &PackageManager,
+) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let (main, build, compile_electron) = match lang {
+    Lang::Ts => (
+      "dist-electron/main.js",
+      "tsc && vite build && tsc -p tsconfig.electron.json",
+      "tsc -p tsconfig.electron.json && ",
+    ),
+    Lang::Js => ("electron/main.js", "vite build", ""),
+  };
+  let command = package_manager.command();
+  let run_build = package_manager.run("build");
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "description": "{app_name} desktop app",
+  "main": "{main}",
+  "scripts": {{
+    "dev": "concurrently -k \"vite\" \"{command}:dev:electron\"",
+    "dev:electron": "wait-on tcp:5173 && {compile_electron}cross-env VITE_DEV_SERVER_URL=http://localhost:5173 electron .",
+    "build": "{build}",
+    "dist": "{run_build} && electron-builder"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+/// Replaces the React Vite config with an `.mjs` one, as the package is not
+/// `"type": "module"` so Electron can load the CommonJS main process.
+fn create_viteconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::remove_file(format!("{app_path}/vite.config.{}", lang.script_ext()))?;
+
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.mjs"));
+  let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
+  let content = r#"import { defineConfig } from "vite";
+import react from "@vitejs/plugin-react";
+
+export default defineConfig({
+  base: "./",
+  plugins: [react()],
+  server: {
+    port: 5173,
+    strictPort: true,
+  },
+});"#;
+
+  viteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.electron.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "target": "ES2022",
+    "module": "CommonJS",
+    "moduleResolution": "Node",
+    "rootDir": "electron",
+    "outDir": "dist-electron",
+    "types": ["node"],
+    "esModuleInterop": true,
+    "skipLibCheck": true,
+    "strict": true
+  },
+  "include": ["electron"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  let tsconfig_node_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.node.json"));
+  let mut tsconfig_node_file = std::fs::File::create(tsconfig_node_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "allowSyntheticDefaultImports": true,
+    "composite": true,
+    "module": "ESNext",
+    "moduleResolution": "Node"
+  },
+  "include": ["vite.config.mjs"]
+}"#;
+
+  tsconfig_node_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_builder_config(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let builder_config_path = std::path::PathBuf::from(format!("{app_path}/electron-builder.json"));
+  let mut builder_config_file = std::fs::File::create(builder_config_path)?;
+  let main_dir = match lang {
+    Lang::Ts => "dist-electron",
+    Lang::Js => "electron",
+  };
+  let content = format!(
+    r#"{{
+  "appId": "com.example.{app_name}",
+  "productName": "{app_name}",
+  "directories": {{
+    "output": "release"
+  }},
+  "files": ["dist/**/*", "{main_dir}/**/*"],
+  "linux": {{
+    "target": ["AppImage", "deb"],
+    "category": "Utility",
+    "maintainer": "{app_name} maintainers <maintainers@example.com>"
+  }}
+}}"#
+  );
+
+  builder_config_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/electron/"))?;
+
+  let ext = lang.script_ext();
+  let maints_path = std::path::PathBuf::from(format!("{app_path}/electron/main.{ext}"));
+  let mut maints_file = std::fs::File::create(maints_path)?;
+
+  let imports = match lang {
+    Lang::Ts => {
+      r#"import { app, BrowserWindow, ipcMain } from "electron";
+import path from "node:path";"#
+    }
+    Lang::Js => {
+      r#"const { app, BrowserWindow, ipcMain } = require("electron");
+const path = require("node:path");"#
+    }
+  };
+  let maints_content = format!(
+    r#"{imports}
+
+const createWindow = () => {{
+  const window = new BrowserWindow({{
+    width: 1024,
+    height: 768,
+    webPreferences: {{
+      preload: path.join(__dirname, "preload.js"),
+      contextIsolation: true,
+      nodeIntegration: false,
+      sandbox: true,
+    }},
+  }});
+
+  if (process.env.VITE_DEV_SERVER_URL) {{
+    window.loadURL(process.env.VITE_DEV_SERVER_URL);
+  }} else {{
+    window.loadFile(path.join(__dirname, "../dist/index.html"));
+  }}
+}};
+
+ipcMain.handle("ping", () => "pong");
+
+app.whenReady().then(() => {{
+  createWindow();
+
+  app.on("activate", () => {{
+    if (BrowserWindow.getAllWindows().length === 0) {{
+      createWindow();
+    }}
+  }});
+}});
+
+app.on("window-all-closed", () => {{
+  if (process.platform !== "darwin") {{
+    app.quit();
+  }}
+}});"#
+  );
+
+  maints_file.write_all(maints_content.as_bytes())?;
+
+  let preloadts_path = std::path::PathBuf::from(format!("{app_path}/electron/preload.{ext}"));
+  let mut preloadts_file = std::fs::File::create(preloadts_path)?;
+
+  let import = match lang {
+    Lang::Ts => r#"import { contextBridge, ipcRenderer } from "electron";"#,
+    Lang::Js => r#"const { contextBridge, ipcRenderer } = require("electron");"#,
+  };
+  let preloadts_content = format!(
+    r#"{import}
+
+contextBridge.exposeInMainWorld("electron", {{
+  ping: () => ipcRenderer.invoke("ping"),
+}});"#
+  );
+
+  preloadts_file.write_all(preloadts_content.as_bytes())?;
+
+  if *lang == Lang::Ts {
+    let electrondts_path = std::path::PathBuf::from(format!("{app_path}/src/electron.d.ts"));
+    let mut electrondts_file = std::fs::File::create(electrondts_path)?;
+
+    let electrondts_content = r#"interface Window {
+  electron: {
+    ping: () => Promise<string>;
+  };
+}"#;
+
+    electrondts_file.write_all(electrondts_content.as_bytes())?;
+  }
+
+  Ok(())
+}
@@ -0,0 +1,238 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::{Lang, TemplateOptions};
+use crate::utils::to_kebab_case;
+
+pub fn create_expo_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options)?;
+  create_app_json(&app_name, app_path, options.expo_router)?;
+  create_babel_config(app_path)?;
+  match options.lang {
+    Lang::Ts => create_tsconfig(app_path)?,
+    Lang::Js => create_jsconfig(app_path)?,
+  };
+
+  if options.expo_router {
+    create_router_folders(app_path, &options.lang)?;
+  } else {
+    create_app_entry(app_path, &options.lang)?;
+  }
+
+  Ok(())
+}
+
+fn create_package_json(
+  app_name: &str,
+  app_path: &str,
+  options: &TemplateOptions,
+) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let main = if options.expo_router {
+    "expo-router/entry".to_owned()
+  } else {
+    format!("index.{}", options.lang.script_ext())
+  };
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "main": "{main}",
+  "scripts": {{
+    "start": "expo start",
+    "android": "expo start --android",
+    "ios": "expo start --ios",
+    "web": "expo start --web"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_app_json(app_name: &str, app_path: &str, expo_router: bool) -> anyhow::Result<()> {
+  let app_json_path = std::path::PathBuf::from(format!("{app_path}/app.json"));
+  let mut app_json_file = std::fs::File::create(app_json_path)?;
+  let slug = to_kebab_case(app_name);
+  let router = if expo_router {
+    format!(
+      r#"
+    "scheme": "{slug}",
+    "plugins": ["expo-router"],"#
+    )
+  } else {
+    String::new()
+  };
+  let content = format!(
+    r#"{{
+  "expo": {{
+    "name": "{app_name}",
+    "slug": "{slug}",
+    "version": "0.1.0",
+    "orientation": "portrait",
+    "userInterfaceStyle": "automatic",{router}
+    "ios": {{
+      "supportsTablet": true
+    }},
+    "web": {{
+      "bundler": "metro"
+    }}
+  }}
+}}"#
+  );
+
+  app_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_babel_config(app_path: &str) -> anyhow::Result<()> {
+  let babel_config_path = std::path::PathBuf::from(format!("{app_path}/babel.config.js"));
+  let mut babel_config_file = std::fs::File::create(babel_config_path)?;
+  let content = r#"module.exports = function (api) {
+  api.cache(true);
+
+  return {
+    presets: ["babel-preset-expo"],
+  };
+};"#;
+
+  babel_config_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "extends": "expo/tsconfig.base",
+  "compilerOptions": {
+    "strict": true,
+    "paths": {
+      "@/*": ["./*"]
+    }
+  },
+  "include": ["**/*.ts", "**/*.tsx", ".expo/types/**/*.ts", "expo-env.d.ts"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_jsconfig(app_path: &str) -> anyhow::Result<()> {
+  let jsconfig_path = std::path::PathBuf::from(format!("{app_path}/jsconfig.json"));
+  let mut jsconfig_file = std::fs::File::create(jsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "jsx": "react-native",
+    "paths": {
+      "@/*": ["./*"]
+    }
+  }
+}"#;
+
+  jsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_app_entry(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let indexts_path = std::path::PathBuf::from(format!("{app_path}/index.{}", lang.script_ext()));
+  let mut indexts_file = std::fs::File::create(indexts_path)?;
+
+  let indexts_content = r#"import { registerRootComponent } from "expo";
+import App from "./App";
+
+registerRootComponent(App);"#;
+
+  indexts_file.write_all(indexts_content.as_bytes())?;
+
+  let apptsx_path = std::path::PathBuf::from(format!("{app_path}/App.{}", lang.jsx_ext()));
+  let mut apptsx_file = std::fs::File::create(apptsx_path)?;
+
+  let apptsx_content = r#"import { StatusBar } from "expo-status-bar";
+import { StyleSheet, Text, View } from "react-native";
+
+export default function App() {
+  return (
+    <View style={styles.container}>
+      <Text>Open up App to start working on your app!</Text>
+      <StatusBar style="auto" />
+    </View>
+  );
+}
+
+const styles = StyleSheet.create({
+  container: {
+    flex: 1,
+    alignItems: "center",
+    justifyContent: "center",
+  },
+});"#;
+
+  apptsx_file.write_all(apptsx_content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_router_folders(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/app/"))?;
+
+  let ext = lang.jsx_ext();
+  let layouttsx_path = std::path::PathBuf::from(format!("{app_path}/app/_layout.{ext}"));
+  let mut layouttsx_file = std::fs::File::create(layouttsx_path)?;
+
+  let layouttsx_content = r#"import { Stack } from "expo-router";
+import { StatusBar } from "expo-status-bar";
+
+export default function RootLayout() {
+  return (
+    <>
+      <Stack />
+      <StatusBar style="auto" />
+    </>
+  );
+}"#;
+
+  layouttsx_file.write_all(layouttsx_content.as_bytes())?;
+
+  let indextsx_path = std::path::PathBuf::from(format!("{app_path}/app/index.{ext}"));
+  let mut indextsx_file = std::fs::File::create(indextsx_path)?;
+
+  let indextsx_content = r#"import { StyleSheet, Text, View } from "react-native";
+
+export default function Index() {
+  return (
+    <View style={styles.container}>
+      <Text>Edit app/index to start working on your app!</Text>
+    </View>
+  );
+}
+
+const styles = StyleSheet.create({
+  container: {
+    flex: 1,
+    alignItems: "center",
+    justifyContent: "center",
+  },
+});"#;
+
+  indextsx_file.write_all(indextsx_content.as_bytes())?;
+
+  Ok(())
+}
@@ -0,0 +1,284 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::{Lang, TemplateOptions};
+
+pub fn create_extension_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options)?;
+  create_tsconfig(app_path, &options.lang)?;
+  create_viteconfig(app_path, &options.lang)?;
+  create_manifest(&app_name, app_path)?;
+  create_zip_script(app_path)?;
+  create_indexhtmls(&app_name, app_path, &options.lang)?;
+  create_folders(app_path, &options.lang)?;
+
+  Ok(())
+}
+
+fn create_package_json(
+  app_name: &str,
+  app_path: &str,
+  options: &TemplateOptions,
+) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let build = match options.lang {
+    Lang::Ts => "tsc && vite build",
+    Lang::Js => "vite build",
+  };
+  let run_build = options.package_manager.run("build");
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "vite build --watch --mode development",
+    "build": "{build}",
+    "zip": "{run_build} && node scripts/zip.js"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let (config_name, types) = match lang {
+    Lang::Ts => ("tsconfig.json", r#"["chrome", "vite/client"]"#),
+    Lang::Js => ("jsconfig.json", r#"["vite/client"]"#),
+  };
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/{config_name}"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = format!(
+    r#"{{
+  "compilerOptions": {{
+    "isolatedModules": true,
+    "jsx": "react-jsx",
+    "lib": ["DOM", "DOM.Iterable", "ESNext"],
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "moduleDetection": "force",
+    "noEmit": true,
+    "skipLibCheck": true,
+    "strict": true,
+    "target": "ESNext",
+    "types": {types}
+  }},
+  "include": ["src"]
+}}"#
+  );
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_viteconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.{ext}"));
+  let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
+  let content = format!(
+    r#"import {{ resolve }} from "node:path";
+import {{ defineConfig }} from "vite";
+import react from "@vitejs/plugin-react";
+
+export default defineConfig({{
+  plugins: [react()],
+  build: {{
+    outDir: "dist",
+    emptyOutDir: true,
+    rollupOptions: {{
+      input: {{
+        popup: resolve(__dirname, "popup.html"),
+        options: resolve(__dirname, "options.html"),
+        background: resolve(__dirname, "src/background.{ext}"),
+        content: resolve(__dirname, "src/content.{ext}"),
+      }},
+      output: {{
+        entryFileNames: "[name].js",
+        chunkFileNames: "chunks/[name]-[hash].js",
+        assetFileNames: "assets/[name]-[hash][extname]",
+      }},
+    }},
+  }},
+}});"#
+  );
+
+  viteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_manifest(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+
+  let manifest_path = std::path::PathBuf::from(format!("{app_path}/public/manifest.json"));
+  let mut manifest_file = std::fs::File::create(manifest_path)?;
+  let content = format!(
+    r#"{{
+  "manifest_version": 3,
+  "name": "{app_name}",
+  "version": "0.1.0",
+  "action": {{
+    "default_popup": "popup.html"
+  }},
+  "options_page": "options.html",
+  "background": {{
+    "service_worker": "background.js",
+    "type": "module"
+  }},
+  "content_scripts": [
+    {{
+      "matches": ["<all_urls>"],
+      "js": ["content.js"]
+    }}
+  ],
+  "permissions": ["storage"]
+}}"#
+  );
+
+  manifest_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_zip_script(app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/scripts/"))?;
+
+  let zip_script_path = std::path::PathBuf::from(format!("{app_path}/scripts/zip.js"));
+  let mut zip_script_file = std::fs::File::create(zip_script_path)?;
+  let content = r#"import { createWriteStream, readFileSync } from "node:fs";
+import archiver from "archiver";
+
+const { name, version } = JSON.parse(readFileSync("dist/manifest.json", "utf8"));
+const output = createWriteStream(`${name}-${version}.zip`);
+const archive = archiver("zip", { zlib: { level: 9 } });
+
+output.on("close", () => {
+  console.log(`Packed ${output.path} (${archive.pointer()} bytes)`);
+});
+
+archive.pipe(output);
+archive.directory("dist/", false);
+await archive.finalize();"#;
+
+  zip_script_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_indexhtmls(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.jsx_ext();
+
+  for page in ["popup", "options"] {
+    let indexhtml_path = std::path::PathBuf::from(format!("{app_path}/{page}.html"));
+    let mut indexhtml_file = std::fs::File::create(indexhtml_path)?;
+
+    let content = format!(
+      r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <title>{app_name}</title>
+  </head>
+  <body>
+    <div id="root"></div>
+    <script type="module" src="/src/{page}/main.{ext}"></script>
+  </body>
+</html>"#
+    );
+
+    indexhtml_file.write_all(content.as_bytes())?;
+  }
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src/components/"))?;
+
+  let script_ext = lang.script_ext();
+  let ext = lang.jsx_ext();
+  let root_element = match lang {
+    Lang::Ts => r#"document.getElementById("root") as HTMLElement"#,
+    Lang::Js => r#"document.getElementById("root")"#,
+  };
+
+  for (page, name) in [("popup", "Popup"), ("options", "Options")] {
+    std::fs::create_dir_all(format!("{app_path}/src/{page}/"))?;
+
+    let maintsx_path = std::path::PathBuf::from(format!("{app_path}/src/{page}/main.{ext}"));
+    let mut maintsx_file = std::fs::File::create(maintsx_path)?;
+
+    let maintsx_content = format!(
+      r#"import React from "react";
+import ReactDOM from "react-dom/client";
+import {name} from "./{name}";
+
+ReactDOM.createRoot({root_element}).render(
+  <React.StrictMode>
+    <{name} />
+  </React.StrictMode>
+);"#
+    );
+
+    maintsx_file.write_all(maintsx_content.as_bytes())?;
+
+    let pagetsx_path = std::path::PathBuf::from(format!("{app_path}/src/{page}/{name}.{ext}"));
+    let mut pagetsx_file = std::fs::File::create(pagetsx_path)?;
+
+    let pagetsx_content = match lang {
+      Lang::Ts => format!(
+        r#"import type {{ FC }} from "react";
+
+const {name}: FC = () => {{
+  return <main></main>;
+}};
+
+export default {name};"#
+      ),
+      Lang::Js => format!(
+        r#"const {name} = () => {{
+  return <main></main>;
+}};
+
+export default {name};"#
+      ),
+    };
+
+    pagetsx_file.write_all(pagetsx_content.as_bytes())?;
+  }
+
+  let backgroundts_path =
+    std::path::PathBuf::from(format!("{app_path}/src/background.{script_ext}"));
+  let mut backgroundts_file = std::fs::File::create(backgroundts_path)?;
+
+  let backgroundts_content = r#"chrome.runtime.onInstalled.addListener(() => {
+  console.log("Extension installed");
+});"#;
+
+  backgroundts_file.write_all(backgroundts_content.as_bytes())?;
+
+  let contentts_path = std::path::PathBuf::from(format!("{app_path}/src/content.{script_ext}"));
+  let mut contentts_file = std::fs::File::create(contentts_path)?;
+
+  let contentts_content = r#"// Content scripts run as classic scripts, so keep this file free of imports.
+console.log("Content script loaded on", location.href);"#;
+
+  contentts_file.write_all(contentts_content.as_bytes())?;
+
+  Ok(())
+}
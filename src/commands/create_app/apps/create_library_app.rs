@@ -0,0 +1,142 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::PackageManager;
+
+pub fn create_library_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options.package_manager)?;
+  create_tsconfig(app_path)?;
+  create_tsup_config(app_path)?;
+  create_folders(app_path)?;
+
+  Ok(())
+}
+
+fn create_package_json(
+  app_name: &str,
+  app_path: &str,
+  package_manager: &PackageManager,
+) -> anyhow::Result<()> {
+  let prepublish = ["typecheck", "test", "build"]
+    .map(|script| package_manager.run(script))
+    .join(" && ");
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "version": "0.1.0",
+  "type": "module",
+  "main": "./dist/index.cjs",
+  "module": "./dist/index.js",
+  "types": "./dist/index.d.ts",
+  "exports": {{
+    ".": {{
+      "import": {{
+        "types": "./dist/index.d.ts",
+        "default": "./dist/index.js"
+      }},
+      "require": {{
+        "types": "./dist/index.d.cts",
+        "default": "./dist/index.cjs"
+      }}
+    }},
+    "./package.json": "./package.json"
+  }},
+  "files": ["dist"],
+  "sideEffects": false,
+  "scripts": {{
+    "build": "tsup",
+    "dev": "tsup --watch",
+    "test": "vitest run",
+    "typecheck": "tsc --noEmit",
+    "prepublishOnly": "{prepublish}"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "target": "ES2020",
+    "lib": ["ES2020"],
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "declaration": true,
+    "isolatedModules": true,
+    "esModuleInterop": true,
+    "forceConsistentCasingInFileNames": true,
+    "noEmit": true,
+    "skipLibCheck": true,
+    "strict": true
+  },
+  "include": ["src"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsup_config(app_path: &str) -> anyhow::Result<()> {
+  let tsup_config_path = std::path::PathBuf::from(format!("{app_path}/tsup.config.ts"));
+  let mut tsup_config_file = std::fs::File::create(tsup_config_path)?;
+  let content = r#"import { defineConfig } from "tsup";
+
+export default defineConfig({
+  entry: ["src/index.ts"],
+  format: ["esm", "cjs"],
+  dts: true,
+  sourcemap: true,
+  clean: true,
+});"#;
+
+  tsup_config_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src/"))?;
+
+  let indexts_path = std::path::PathBuf::from(format!("{app_path}/src/index.ts"));
+  let mut indexts_file = std::fs::File::create(indexts_path)?;
+
+  let indexts_content = r#"export const greet = (name: string): string => {
+  return `Hello, ${name}!`;
+};"#;
+
+  indexts_file.write_all(indexts_content.as_bytes())?;
+
+  let indextest_path = std::path::PathBuf::from(format!("{app_path}/src/index.test.ts"));
+  let mut indextest_file = std::fs::File::create(indextest_path)?;
+
+  let indextest_content = r#"import { describe, expect, it } from "vitest";
+import { greet } from "./index";
+
+describe("greet", () => {
+  it("greets by name", () => {
+    expect(greet("deez")).toBe("Hello, deez!");
+  });
+});"#;
+
+  indextest_file.write_all(indextest_content.as_bytes())?;
+
+  Ok(())
+}
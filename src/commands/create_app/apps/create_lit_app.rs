@@ -0,0 +1,222 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::Lang;
+
+pub fn create_lit_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options.lang)?;
+  match options.lang {
+    Lang::Ts => create_tsconfig(app_path)?,
+    Lang::Js => create_jsconfig(app_path)?,
+  };
+  create_viteconfig(app_path, &options.lang)?;
+  create_indexhtml(&app_name, app_path, &options.lang)?;
+  create_folders(app_path, &options.lang)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let build = match lang {
+    Lang::Ts => "tsc && vite build",
+    Lang::Js => "vite build",
+  };
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "vite",
+    "build": "{build}",
+    "preview": "vite preview"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "experimentalDecorators": true,
+    "isolatedModules": true,
+    "lib": ["DOM", "DOM.Iterable", "ES2020"],
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "noEmit": true,
+    "skipLibCheck": true,
+    "strict": true,
+    "target": "ES2020",
+    "useDefineForClassFields": false
+  },
+  "include": ["src"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_jsconfig(app_path: &str) -> anyhow::Result<()> {
+  let jsconfig_path = std::path::PathBuf::from(format!("{app_path}/jsconfig.json"));
+  let mut jsconfig_file = std::fs::File::create(jsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "checkJs": false,
+    "experimentalDecorators": true,
+    "lib": ["DOM", "DOM.Iterable", "ES2020"],
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "skipLibCheck": true,
+    "target": "ES2020",
+    "useDefineForClassFields": false
+  },
+  "include": ["src", "vite.config.js"]
+}"#;
+
+  jsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_viteconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.{ext}"));
+  let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
+  let content = format!(
+    r#"import {{ defineConfig }} from "vite";
+
+export default defineConfig({{
+  build: {{
+    lib: {{
+      entry: "src/my-element.{ext}",
+      formats: ["es"],
+    }},
+  }},
+}});"#
+  );
+
+  viteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_indexhtml(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let indexhtml_path = std::path::PathBuf::from(format!("{app_path}/index.html"));
+  let mut indexhtml_file = std::fs::File::create(indexhtml_path)?;
+
+  let content = format!(
+    r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>{app_name}</title>
+    <script type="module" src="/src/my-element.{ext}"></script>
+  </head>
+  <body>
+    <my-element></my-element>
+  </body>
+</html>"#
+  );
+
+  indexhtml_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/components/"))?;
+
+  if *lang == Lang::Ts {
+    let viteenv_path = std::path::PathBuf::from(format!("{app_path}/src/vite-env.d.ts"));
+    let mut viteenv_file = std::fs::File::create(viteenv_path)?;
+
+    let viteenv_content = r#"/// <reference types="vite/client" />"#;
+
+    viteenv_file.write_all(viteenv_content.as_bytes())?;
+  }
+
+  let ext = lang.script_ext();
+  let myelement_path = std::path::PathBuf::from(format!("{app_path}/src/my-element.{ext}"));
+  let mut myelement_file = std::fs::File::create(myelement_path)?;
+
+  let myelement_content = match lang {
+    Lang::Ts => {
+      r#"import { LitElement, css, html } from "lit";
+import { customElement, property } from "lit/decorators.js";
+
+@customElement("my-element")
+export class MyElement extends LitElement {
+  static styles = css`
+    :host {
+      display: block;
+    }
+  `;
+
+  @property()
+  name = "World";
+
+  render() {
+    return html`<p>Hello, ${this.name}!</p>`;
+  }
+}
+
+declare global {
+  interface HTMLElementTagNameMap {
+    "my-element": MyElement;
+  }
+}"#
+    }
+    Lang::Js => {
+      r#"import { LitElement, css, html } from "lit";
+
+export class MyElement extends LitElement {
+  static styles = css`
+    :host {
+      display: block;
+    }
+  `;
+
+  static properties = {
+    name: {},
+  };
+
+  constructor() {
+    super();
+    this.name = "World";
+  }
+
+  render() {
+    return html`<p>Hello, ${this.name}!</p>`;
+  }
+}
+
+customElements.define("my-element", MyElement);"#
+    }
+  };
+
+  myelement_file.write_all(myelement_content.as_bytes())?;
+
+  Ok(())
+}
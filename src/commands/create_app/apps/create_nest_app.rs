@@ -0,0 +1,216 @@
+use std::io::Write;
+
+use super::AppWithPath;
+
+pub fn create_nest_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name, app_path, ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path)?;
+  create_nest_cli_json(app_path)?;
+  create_tsconfig(app_path)?;
+  create_folders(app_path)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "scripts": {{
+    "build": "nest build",
+    "dev": "nest start --watch",
+    "start": "nest start",
+    "start:prod": "node dist/main",
+    "test": "jest"
+  }},
+  "jest": {{
+    "moduleFileExtensions": ["js", "json", "ts"],
+    "rootDir": "src",
+    "testRegex": ".*\\.spec\\.ts$",
+    "transform": {{
+      "^.+\\.(t|j)s$": "ts-jest"
+    }},
+    "testEnvironment": "node"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_nest_cli_json(app_path: &str) -> anyhow::Result<()> {
+  let nest_cli_json_path = std::path::PathBuf::from(format!("{app_path}/nest-cli.json"));
+  let mut nest_cli_json_file = std::fs::File::create(nest_cli_json_path)?;
+  let content = r#"{
+  "$schema": "https://json.schemastore.org/nest-cli",
+  "collection": "@nestjs/schematics",
+  "sourceRoot": "src",
+  "compilerOptions": {
+    "deleteOutDir": true
+  }
+}"#;
+
+  nest_cli_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "module": "commonjs",
+    "declaration": true,
+    "removeComments": true,
+    "emitDecoratorMetadata": true,
+    "experimentalDecorators": true,
+    "allowSyntheticDefaultImports": true,
+    "target": "ES2021",
+    "sourceMap": true,
+    "outDir": "./dist",
+    "baseUrl": "./",
+    "incremental": true,
+    "skipLibCheck": true,
+    "strictNullChecks": true,
+    "noImplicitAny": true,
+    "forceConsistentCasingInFileNames": true
+  }
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  let tsconfig_build_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.build.json"));
+  let mut tsconfig_build_file = std::fs::File::create(tsconfig_build_path)?;
+  let content = r#"{
+  "extends": "./tsconfig.json",
+  "exclude": ["node_modules", "test", "dist", "**/*spec.ts"]
+}"#;
+
+  tsconfig_build_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src/health/"))?;
+
+  let maints_path = std::path::PathBuf::from(format!("{app_path}/src/main.ts"));
+  let mut maints_file = std::fs::File::create(maints_path)?;
+
+  let maints_content = r#"import { NestFactory } from "@nestjs/core";
+import { AppModule } from "./app.module";
+
+const bootstrap = async () => {
+  const app = await NestFactory.create(AppModule);
+
+  await app.listen(process.env.PORT ?? 3000);
+};
+
+bootstrap();"#;
+
+  maints_file.write_all(maints_content.as_bytes())?;
+
+  let appmodule_path = std::path::PathBuf::from(format!("{app_path}/src/app.module.ts"));
+  let mut appmodule_file = std::fs::File::create(appmodule_path)?;
+
+  let appmodule_content = r#"import { Module } from "@nestjs/common";
+import { HealthModule } from "./health/health.module";
+
+@Module({
+  imports: [HealthModule],
+})
+export class AppModule {}"#;
+
+  appmodule_file.write_all(appmodule_content.as_bytes())?;
+
+  let healthmodule_path =
+    std::path::PathBuf::from(format!("{app_path}/src/health/health.module.ts"));
+  let mut healthmodule_file = std::fs::File::create(healthmodule_path)?;
+
+  let healthmodule_content = r#"import { Module } from "@nestjs/common";
+import { HealthController } from "./health.controller";
+import { HealthService } from "./health.service";
+
+@Module({
+  controllers: [HealthController],
+  providers: [HealthService],
+})
+export class HealthModule {}"#;
+
+  healthmodule_file.write_all(healthmodule_content.as_bytes())?;
+
+  let healthcontroller_path =
+    std::path::PathBuf::from(format!("{app_path}/src/health/health.controller.ts"));
+  let mut healthcontroller_file = std::fs::File::create(healthcontroller_path)?;
+
+  let healthcontroller_content = r#"import { Controller, Get } from "@nestjs/common";
+import { HealthService } from "./health.service";
+
+@Controller("health")
+export class HealthController {
+  constructor(private readonly healthService: HealthService) {}
+
+  @Get()
+  check() {
+    return this.healthService.check();
+  }
+}"#;
+
+  healthcontroller_file.write_all(healthcontroller_content.as_bytes())?;
+
+  let healthservice_path =
+    std::path::PathBuf::from(format!("{app_path}/src/health/health.service.ts"));
+  let mut healthservice_file = std::fs::File::create(healthservice_path)?;
+
+  let healthservice_content = r#"import { Injectable } from "@nestjs/common";
+
+@Injectable()
+export class HealthService {
+  check() {
+    return { status: "ok" };
+  }
+}"#;
+
+  healthservice_file.write_all(healthservice_content.as_bytes())?;
+
+  let healthspec_path =
+    std::path::PathBuf::from(format!("{app_path}/src/health/health.controller.spec.ts"));
+  let mut healthspec_file = std::fs::File::create(healthspec_path)?;
+
+  let healthspec_content = r#"import { Test } from "@nestjs/testing";
+import { HealthController } from "./health.controller";
+import { HealthService } from "./health.service";
+
+describe("HealthController", () => {
+  let controller: HealthController;
+
+  beforeEach(async () => {
+    const module = await Test.createTestingModule({
+      controllers: [HealthController],
+      providers: [HealthService],
+    }).compile();
+
+    controller = module.get(HealthController);
+  });
+
+  it("reports ok", () => {
+    expect(controller.check()).toEqual({ status: "ok" });
+  });
+});"#;
+
+  healthspec_file.write_all(healthspec_content.as_bytes())?;
+
+  Ok(())
+}
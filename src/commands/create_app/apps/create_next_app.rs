@@ -1,19 +1,38 @@
 use std::io::Write;
 
 use super::AppWithPath;
+use crate::cli::{Lang, NextRouter, TemplateOptions};
 
 pub fn create_next_app(app: AppWithPath) -> anyhow::Result<()> {
   let AppWithPath {
-    app_name, app_path, ..
+    app_name,
+    app_path,
+    options,
+    ..
   } = app;
 
   let app_path = app_path.to_str().unwrap();
+  let src_path = if options.src_dir {
+    format!("{app_path}/src")
+  } else {
+    app_path.to_owned()
+  };
 
   create_package_json(&app_name, app_path)?;
-  create_tsconfig(app_path)?;
+  match options.lang {
+    Lang::Ts => {
+      create_tsconfig(app_path, &options)?;
+      create_nextenv(app_path)?;
+    }
+    Lang::Js => create_jsconfig(app_path, &options)?,
+  };
   create_nextconfig(app_path)?;
-  create_nextenv(app_path)?;
-  create_folders(&app_name, app_path)?;
+  create_shared_folders(app_path, &src_path, &options.lang)?;
+
+  match options.router {
+    NextRouter::Pages => create_pages_folders(&app_name, &src_path, &options.lang)?,
+    NextRouter::App => create_app_folders(&app_name, &src_path, &options.lang)?,
+  };
 
   Ok(())
 }
@@ -41,12 +60,22 @@ fn create_package_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
   Ok(())
 }
 
-fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+fn create_tsconfig(app_path: &str, options: &TemplateOptions) -> anyhow::Result<()> {
   let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
   let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
-  let content = r#"{
-  "compilerOptions": {
-    "target": "es5",
+  let src = if options.src_dir { "src/" } else { "" };
+  let (plugins, next_types) = match options.router {
+    NextRouter::Pages => ("", ""),
+    NextRouter::App => (
+      r#"
+    "plugins": [{ "name": "next" }],"#,
+      r#", ".next/types/**/*.ts""#,
+    ),
+  };
+  let content = format!(
+    r#"{{
+  "compilerOptions": {{
+    "target": "ES2017",
     "lib": ["dom", "dom.iterable", "esnext"],
     "allowJs": true,
     "skipLibCheck": true,
@@ -55,35 +84,58 @@ fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
     "noEmit": true,
     "esModuleInterop": true,
     "module": "esnext",
-    "moduleResolution": "node",
+    "moduleResolution": "bundler",
     "resolveJsonModule": true,
     "isolatedModules": true,
     "jsx": "preserve",
-    "incremental": true,
+    "incremental": true,{plugins}
     "baseUrl": ".",
-    "paths": {
-      "@/*": ["components/*"],
-      "@utils/*": ["utils/*"],
+    "paths": {{
+      "@/*": ["{src}components/*"],
+      "@utils/*": ["{src}utils/*"],
       "@public/*": ["public/*"],
-      "@styles/*": ["styles/*"]
-    }
-  },
-  "include": ["next-env.d.ts", "**/*.ts", "**/*.tsx"],
+      "@styles/*": ["{src}styles/*"]
+    }}
+  }},
+  "include": ["next-env.d.ts", "**/*.ts", "**/*.tsx"{next_types}],
   "exclude": ["node_modules"]
-}"#;
+}}"#
+  );
 
   tsconfig_file.write_all(content.as_bytes())?;
 
   Ok(())
 }
 
+fn create_jsconfig(app_path: &str, options: &TemplateOptions) -> anyhow::Result<()> {
+  let jsconfig_path = std::path::PathBuf::from(format!("{app_path}/jsconfig.json"));
+  let mut jsconfig_file = std::fs::File::create(jsconfig_path)?;
+  let src = if options.src_dir { "src/" } else { "" };
+  let content = format!(
+    r#"{{
+  "compilerOptions": {{
+    "baseUrl": ".",
+    "paths": {{
+      "@/*": ["{src}components/*"],
+      "@utils/*": ["{src}utils/*"],
+      "@public/*": ["public/*"],
+      "@styles/*": ["{src}styles/*"]
+    }}
+  }}
+}}"#
+  );
+
+  jsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
 fn create_nextconfig(app_path: &str) -> anyhow::Result<()> {
   let nextconfig_path = std::path::PathBuf::from(format!("{app_path}/next.config.js"));
   let mut nextconfig_file = std::fs::File::create(nextconfig_path)?;
   let content = r#"/** @type {import('next').NextConfig} */
 const nextConfig = {
   reactStrictMode: true,
-  swcMinify: true,
 }
 
 module.exports = nextConfig"#;
@@ -104,34 +156,73 @@ fn create_nextenv(app_path: &str) -> anyhow::Result<()> {
   Ok(())
 }
 
-fn create_folders(app_name: &str, app_path: &str) -> anyhow::Result<()> {
-  std::fs::create_dir_all(format!("{app_path}/pages/api/"))?;
+fn create_shared_folders(app_path: &str, src_path: &str, lang: &Lang) -> anyhow::Result<()> {
   std::fs::create_dir_all(format!("{app_path}/public/"))?;
-  std::fs::create_dir_all(format!("{app_path}/components/"))?;
-  std::fs::create_dir_all(format!("{app_path}/styles/"))?;
-  let globalscss_path = std::path::PathBuf::from(format!("{app_path}/styles/globals.css"));
+  std::fs::create_dir_all(format!("{src_path}/components/"))?;
+  std::fs::create_dir_all(format!("{src_path}/styles/"))?;
+  let globalscss_path = std::path::PathBuf::from(format!("{src_path}/styles/globals.css"));
   std::fs::File::create(globalscss_path)?;
 
-  let apptsx_path = std::path::PathBuf::from(format!("{app_path}/pages/_app.tsx"));
+  let ext = lang.jsx_ext();
+  let layouttsx_path = std::path::PathBuf::from(format!("{src_path}/components/Layout.{ext}"));
+  let mut layouttsx_file = std::fs::File::create(layouttsx_path)?;
+
+  let layouttsx_content = match lang {
+    Lang::Ts => {
+      r#"import type { FC, ReactNode } from "react";
+
+interface IProps {
+  children: ReactNode;
+}
+
+const Layout: FC<IProps> = ({ children }) => <main>{children}</main>;
+
+export default Layout;"#
+    }
+    Lang::Js => {
+      r#"const Layout = ({ children }) => <main>{children}</main>;
+
+export default Layout;"#
+    }
+  };
+
+  layouttsx_file.write_all(layouttsx_content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_pages_folders(app_name: &str, src_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{src_path}/pages/api/"))?;
+
+  let ext = lang.jsx_ext();
+  let apptsx_path = std::path::PathBuf::from(format!("{src_path}/pages/_app.{ext}"));
   let mut apptsx_file = std::fs::File::create(apptsx_path)?;
 
-  let apptsx_content = r#"import type { AppProps } from "next/app";
-import Layout from "../components/Layout";
+  let (props_import, props_type) = match lang {
+    Lang::Ts => (
+      "import type { AppProps } from \"next/app\";\n",
+      ": AppProps",
+    ),
+    Lang::Js => ("", ""),
+  };
+  let apptsx_content = format!(
+    r#"{props_import}import Layout from "../components/Layout";
 import "@styles/globals.css";
 
-const App = ({ Component, pageProps }: AppProps) => {
+const App = ({{ Component, pageProps }}{props_type}) => {{
   return (
     <Layout>
-      <Component {...pageProps} />
+      <Component {{...pageProps}} />
     </Layout>
   );
-};
+}};
 
-export default App;"#;
+export default App;"#
+  );
 
   apptsx_file.write_all(apptsx_content.as_bytes())?;
 
-  let indextsx_path = std::path::PathBuf::from(format!("{app_path}/pages/index.tsx"));
+  let indextsx_path = std::path::PathBuf::from(format!("{src_path}/pages/index.{ext}"));
   let mut indextsx_file = std::fs::File::create(indextsx_path)?;
 
   let indextsx_content = format!(
@@ -153,20 +244,67 @@ export default Home;"#,
 
   indextsx_file.write_all(indextsx_content.as_bytes())?;
 
-  let layouttsx_path = std::path::PathBuf::from(format!("{app_path}/components/Layout.tsx"));
+  Ok(())
+}
+
+fn create_app_folders(app_name: &str, src_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{src_path}/app/api/hello/"))?;
+
+  let ext = lang.jsx_ext();
+  let layouttsx_path = std::path::PathBuf::from(format!("{src_path}/app/layout.{ext}"));
   let mut layouttsx_file = std::fs::File::create(layouttsx_path)?;
 
-  let layouttsx_content = r#"import type { FC, ReactNode } from "react";
+  let (type_imports, metadata_type, children_type) = match lang {
+    Lang::Ts => (
+      "import type { Metadata } from \"next\";\nimport type { ReactNode } from \"react\";\n",
+      ": Metadata",
+      ": { children: ReactNode }",
+    ),
+    Lang::Js => ("", "", ""),
+  };
+  let layouttsx_content = format!(
+    r#"{type_imports}import Layout from "@/Layout";
+import "@styles/globals.css";
 
-interface IProps {
-  children: ReactNode;
-}
+export const metadata{metadata_type} = {{
+  title: "{app_name}",
+}};
 
-const Layout: FC<IProps> = ({ children }) => <main>{children}</main>;
+const RootLayout = ({{ children }}{children_type}) => {{
+  return (
+    <html lang="en">
+      <body>
+        <Layout>{{children}}</Layout>
+      </body>
+    </html>
+  );
+}};
 
-export default Layout;"#;
+export default RootLayout;"#
+  );
 
   layouttsx_file.write_all(layouttsx_content.as_bytes())?;
 
+  let pagetsx_path = std::path::PathBuf::from(format!("{src_path}/app/page.{ext}"));
+  let mut pagetsx_file = std::fs::File::create(pagetsx_path)?;
+
+  let pagetsx_content = r#"const Home = () => {
+  return <div></div>;
+};
+
+export default Home;"#;
+
+  pagetsx_file.write_all(pagetsx_content.as_bytes())?;
+
+  let ext = lang.script_ext();
+  let routets_path = std::path::PathBuf::from(format!("{src_path}/app/api/hello/route.{ext}"));
+  let mut routets_file = std::fs::File::create(routets_path)?;
+
+  let routets_content = r#"export const GET = () => {
+  return Response.json({ hello: "world" });
+};"#;
+
+  routets_file.write_all(routets_content.as_bytes())?;
+
   Ok(())
 }
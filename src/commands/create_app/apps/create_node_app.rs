@@ -0,0 +1,189 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::ServerFramework;
+
+pub fn create_node_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path)?;
+  create_tsconfig(app_path)?;
+  create_env(app_path)?;
+  create_folders(app_path, &options.server)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "tsx watch src/server.ts",
+    "build": "tsc",
+    "start": "node dist/server.js"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "target": "ES2022",
+    "lib": ["ES2022"],
+    "module": "NodeNext",
+    "moduleResolution": "NodeNext",
+    "types": ["node"],
+    "rootDir": "src",
+    "outDir": "dist",
+    "sourceMap": true,
+    "esModuleInterop": true,
+    "forceConsistentCasingInFileNames": true,
+    "resolveJsonModule": true,
+    "skipLibCheck": true,
+    "strict": true
+  },
+  "include": ["src"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_env(app_path: &str) -> anyhow::Result<()> {
+  let content = r"HOST=0.0.0.0
+PORT=3000";
+
+  for file_name in [".env", ".env.example"] {
+    let env_path = std::path::PathBuf::from(format!("{app_path}/{file_name}"));
+    let mut env_file = std::fs::File::create(env_path)?;
+
+    env_file.write_all(content.as_bytes())?;
+  }
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str, server: &ServerFramework) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src/routes/"))?;
+
+  let envts_path = std::path::PathBuf::from(format!("{app_path}/src/env.ts"));
+  let mut envts_file = std::fs::File::create(envts_path)?;
+
+  let envts_content = r#"import "dotenv/config";
+
+export const env = {
+  HOST: process.env.HOST ?? "0.0.0.0",
+  PORT: Number(process.env.PORT ?? 3000),
+  NODE_ENV: process.env.NODE_ENV ?? "development",
+};"#;
+
+  envts_file.write_all(envts_content.as_bytes())?;
+
+  let serverts_path = std::path::PathBuf::from(format!("{app_path}/src/server.ts"));
+  let mut serverts_file = std::fs::File::create(serverts_path)?;
+
+  let serverts_content = match server {
+    ServerFramework::Express => {
+      r#"import express from "express";
+import { env } from "./env.js";
+import { healthRouter } from "./routes/health.js";
+
+const app = express();
+
+app.use(express.json());
+app.use("/health", healthRouter);
+
+app.listen(env.PORT, env.HOST, () => {
+  console.log(`Server listening on http://${env.HOST}:${env.PORT}`);
+});"#
+    }
+    ServerFramework::Fastify => {
+      r#"import Fastify from "fastify";
+import { env } from "./env.js";
+import { healthRoutes } from "./routes/health.js";
+
+const app = Fastify({ logger: true });
+
+app.register(healthRoutes, { prefix: "/health" });
+
+app.listen({ host: env.HOST, port: env.PORT }).catch((error) => {
+  app.log.error(error);
+  process.exit(1);
+});"#
+    }
+    ServerFramework::Hono => {
+      r#"import { serve } from "@hono/node-server";
+import { Hono } from "hono";
+import { env } from "./env.js";
+import { healthRoutes } from "./routes/health.js";
+
+const app = new Hono();
+
+app.route("/health", healthRoutes);
+
+serve({ fetch: app.fetch, hostname: env.HOST, port: env.PORT }, (info) => {
+  console.log(`Server listening on http://${info.address}:${info.port}`);
+});"#
+    }
+  };
+
+  serverts_file.write_all(serverts_content.as_bytes())?;
+
+  let healthts_path = std::path::PathBuf::from(format!("{app_path}/src/routes/health.ts"));
+  let mut healthts_file = std::fs::File::create(healthts_path)?;
+
+  let healthts_content = match server {
+    ServerFramework::Express => {
+      r#"import { Router } from "express";
+
+export const healthRouter = Router();
+
+healthRouter.get("/", (_req, res) => {
+  res.json({ status: "ok" });
+});"#
+    }
+    ServerFramework::Fastify => {
+      r#"import type { FastifyPluginAsync } from "fastify";
+
+export const healthRoutes: FastifyPluginAsync = async (app) => {
+  app.get("/", async () => {
+    return { status: "ok" };
+  });
+};"#
+    }
+    ServerFramework::Hono => {
+      r#"import { Hono } from "hono";
+
+export const healthRoutes = new Hono();
+
+healthRoutes.get("/", (c) => {
+  return c.json({ status: "ok" });
+});"#
+    }
+  };
+
+  healthts_file.write_all(healthts_content.as_bytes())?;
+
+  Ok(())
+}
@@ -0,0 +1,136 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::Lang;
+
+pub fn create_nuxt_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path)?;
+  create_tsconfig(app_path, &options.lang)?;
+  create_nuxtconfig(app_path, &options.lang)?;
+  create_folders(&app_name, app_path, &options.lang)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "nuxi dev",
+    "build": "nuxi build",
+    "generate": "nuxi generate",
+    "preview": "nuxi preview",
+    "postinstall": "nuxi prepare"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let config_name = match lang {
+    Lang::Ts => "tsconfig.json",
+    Lang::Js => "jsconfig.json",
+  };
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/{config_name}"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "extends": "./.nuxt/tsconfig.json"
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_nuxtconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let nuxtconfig_path = std::path::PathBuf::from(format!("{app_path}/nuxt.config.{ext}"));
+  let mut nuxtconfig_file = std::fs::File::create(nuxtconfig_path)?;
+  let content = r#"export default defineNuxtConfig({
+  devtools: { enabled: true },
+});"#;
+
+  nuxtconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+  std::fs::create_dir_all(format!("{app_path}/pages/"))?;
+  std::fs::create_dir_all(format!("{app_path}/components/"))?;
+  std::fs::create_dir_all(format!("{app_path}/layouts/"))?;
+  std::fs::create_dir_all(format!("{app_path}/server/api/"))?;
+
+  let appvue_path = std::path::PathBuf::from(format!("{app_path}/app.vue"));
+  let mut appvue_file = std::fs::File::create(appvue_path)?;
+
+  let appvue_content = r#"<template>
+  <NuxtLayout>
+    <NuxtPage />
+  </NuxtLayout>
+</template>"#;
+
+  appvue_file.write_all(appvue_content.as_bytes())?;
+
+  let layoutvue_path = std::path::PathBuf::from(format!("{app_path}/layouts/default.vue"));
+  let mut layoutvue_file = std::fs::File::create(layoutvue_path)?;
+
+  let layoutvue_content = r#"<template>
+  <main>
+    <slot />
+  </main>
+</template>"#;
+
+  layoutvue_file.write_all(layoutvue_content.as_bytes())?;
+
+  let indexvue_path = std::path::PathBuf::from(format!("{app_path}/pages/index.vue"));
+  let mut indexvue_file = std::fs::File::create(indexvue_path)?;
+
+  let script_lang = match lang {
+    Lang::Ts => r#" lang="ts""#,
+    Lang::Js => "",
+  };
+  let indexvue_content = format!(
+    r#"<script setup{script_lang}>
+useHead({{ title: "{app_name}" }});
+</script>
+
+<template>
+  <div></div>
+</template>"#
+  );
+
+  indexvue_file.write_all(indexvue_content.as_bytes())?;
+
+  let ext = lang.script_ext();
+  let hellots_path = std::path::PathBuf::from(format!("{app_path}/server/api/hello.{ext}"));
+  let mut hellots_file = std::fs::File::create(hellots_path)?;
+
+  let hellots_content = r#"export default defineEventHandler(() => {
+  return { hello: "world" };
+});"#;
+
+  hellots_file.write_all(hellots_content.as_bytes())?;
+
+  Ok(())
+}
@@ -0,0 +1,209 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::{Lang, PackageKind};
+
+pub fn create_package_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  match options.package_kind {
+    PackageKind::React => {
+      create_react_package_json(&app_name, app_path, &options.lang)?;
+      if options.lang == Lang::Ts {
+        create_react_tsconfig(app_path)?;
+      }
+      create_react_folders(app_path, &options.lang)?;
+    }
+    PackageKind::Tsconfig => create_tsconfig_preset(&app_name, app_path)?,
+    PackageKind::Eslint => create_eslint_preset(&app_name, app_path)?,
+  };
+
+  Ok(())
+}
+
+fn create_react_package_json(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let script_ext = lang.script_ext();
+  let ext = lang.jsx_ext();
+  let scripts = match lang {
+    Lang::Ts => {
+      r#"
+  "scripts": {
+    "typecheck": "tsc --noEmit"
+  },"#
+    }
+    Lang::Js => "",
+  };
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "version": "0.0.0",
+  "private": true,
+  "type": "module",
+  "exports": {{
+    ".": "./src/index.{script_ext}",
+    "./*": "./src/*.{ext}"
+  }},{scripts}
+  "peerDependencies": {{
+    "react": ">=18"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_react_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "jsx": "react-jsx",
+    "lib": ["DOM", "DOM.Iterable", "ESNext"],
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "noEmit": true,
+    "target": "ESNext"
+  },
+  "include": ["src"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_react_folders(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src/"))?;
+
+  let buttontsx_path =
+    std::path::PathBuf::from(format!("{app_path}/src/button.{}", lang.jsx_ext()));
+  let mut buttontsx_file = std::fs::File::create(buttontsx_path)?;
+
+  let buttontsx_content = match lang {
+    Lang::Ts => {
+      r#"import type { ButtonHTMLAttributes } from "react";
+
+export function Button(props: ButtonHTMLAttributes<HTMLButtonElement>) {
+  return <button type="button" {...props} />;
+}"#
+    }
+    Lang::Js => {
+      r#"export function Button(props) {
+  return <button type="button" {...props} />;
+}"#
+    }
+  };
+
+  buttontsx_file.write_all(buttontsx_content.as_bytes())?;
+
+  let indexts_path =
+    std::path::PathBuf::from(format!("{app_path}/src/index.{}", lang.script_ext()));
+  let mut indexts_file = std::fs::File::create(indexts_path)?;
+  let indexts_content = r#"export { Button } from "./button";"#;
+
+  indexts_file.write_all(indexts_content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig_preset(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "version": "0.0.0",
+  "private": true,
+  "files": ["*.json"]
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  let base_json_path = std::path::PathBuf::from(format!("{app_path}/base.json"));
+  let mut base_json_file = std::fs::File::create(base_json_path)?;
+  let content = r#"{
+  "$schema": "https://json.schemastore.org/tsconfig",
+  "compilerOptions": {
+    "esModuleInterop": true,
+    "forceConsistentCasingInFileNames": true,
+    "isolatedModules": true,
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "resolveJsonModule": true,
+    "skipLibCheck": true,
+    "strict": true,
+    "target": "ES2022"
+  }
+}"#;
+
+  base_json_file.write_all(content.as_bytes())?;
+
+  let react_json_path = std::path::PathBuf::from(format!("{app_path}/react.json"));
+  let mut react_json_file = std::fs::File::create(react_json_path)?;
+  let content = r#"{
+  "$schema": "https://json.schemastore.org/tsconfig",
+  "extends": "./base.json",
+  "compilerOptions": {
+    "jsx": "react-jsx",
+    "lib": ["DOM", "DOM.Iterable", "ESNext"]
+  }
+}"#;
+
+  react_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_eslint_preset(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "version": "0.0.0",
+  "private": true,
+  "type": "module",
+  "exports": {{
+    ".": "./index.js"
+  }},
+  "peerDependencies": {{
+    "eslint": ">=9"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  let indexjs_path = std::path::PathBuf::from(format!("{app_path}/index.js"));
+  let mut indexjs_file = std::fs::File::create(indexjs_path)?;
+  let content = r#"import js from "@eslint/js";
+import globals from "globals";
+import tseslint from "typescript-eslint";
+
+export default tseslint.config(
+  { ignores: ["dist", "build", ".next"] },
+  js.configs.recommended,
+  ...tseslint.configs.recommended,
+  {
+    languageOptions: {
+      globals: { ...globals.browser, ...globals.node },
+    },
+  },
+);"#;
+
+  indexjs_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
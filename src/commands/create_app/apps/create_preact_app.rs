@@ -0,0 +1,181 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::Lang;
+
+pub fn create_preact_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options.lang)?;
+  create_tsconfig(app_path, &options.lang)?;
+  create_viteconfig(app_path, &options.lang)?;
+  create_indexhtml(&app_name, app_path, &options.lang)?;
+  create_folders(app_path, &options.lang)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let build = match lang {
+    Lang::Ts => "tsc && vite build",
+    Lang::Js => "vite build",
+  };
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "vite",
+    "build": "{build}",
+    "preview": "vite preview"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let config_name = match lang {
+    Lang::Ts => "tsconfig.json",
+    Lang::Js => "jsconfig.json",
+  };
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/{config_name}"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "allowSyntheticDefaultImports": true,
+    "isolatedModules": true,
+    "jsx": "react-jsx",
+    "jsxImportSource": "preact",
+    "lib": ["DOM", "DOM.Iterable", "ESNext"],
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "noEmit": true,
+    "skipLibCheck": true,
+    "strict": true,
+    "target": "ESNext",
+    "paths": {
+      "react": ["./node_modules/preact/compat/"],
+      "react-dom": ["./node_modules/preact/compat/"]
+    }
+  },
+  "include": ["src"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_viteconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.{ext}"));
+  let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
+  let content = r#"import { defineConfig } from "vite";
+import preact from "@preact/preset-vite";
+
+export default defineConfig({
+  plugins: [preact()],
+});"#;
+
+  viteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_indexhtml(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.jsx_ext();
+  let indexhtml_path = std::path::PathBuf::from(format!("{app_path}/index.html"));
+  let mut indexhtml_file = std::fs::File::create(indexhtml_path)?;
+
+  let content = format!(
+    r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>{app_name}</title>
+  </head>
+  <body>
+    <div id="app"></div>
+    <script type="module" src="/src/main.{ext}"></script>
+  </body>
+</html>"#
+  );
+
+  indexhtml_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/assets/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/components/"))?;
+
+  if *lang == Lang::Ts {
+    let viteenv_path = std::path::PathBuf::from(format!("{app_path}/src/vite-env.d.ts"));
+    let mut viteenv_file = std::fs::File::create(viteenv_path)?;
+
+    let viteenv_content = r#"/// <reference types="vite/client" />"#;
+
+    viteenv_file.write_all(viteenv_content.as_bytes())?;
+  }
+
+  let ext = lang.jsx_ext();
+  let maintsx_path = std::path::PathBuf::from(format!("{app_path}/src/main.{ext}"));
+  let mut maintsx_file = std::fs::File::create(maintsx_path)?;
+
+  let root_element = match lang {
+    Lang::Ts => r#"document.getElementById("app") as HTMLElement"#,
+    Lang::Js => r#"document.getElementById("app")"#,
+  };
+  let maintsx_content = format!(
+    r#"import {{ render }} from "preact";
+import App from "./App";
+
+render(<App />, {root_element});"#
+  );
+
+  maintsx_file.write_all(maintsx_content.as_bytes())?;
+
+  let apptsx_path = std::path::PathBuf::from(format!("{app_path}/src/App.{ext}"));
+  let mut apptsx_file = std::fs::File::create(apptsx_path)?;
+
+  let apptsx_content = match lang {
+    Lang::Ts => {
+      r#"import type { FunctionComponent } from "preact";
+
+const App: FunctionComponent = () => {
+  return <main></main>;
+};
+
+export default App;"#
+    }
+    Lang::Js => {
+      r#"const App = () => {
+  return <main></main>;
+};
+
+export default App;"#
+    }
+  };
+
+  apptsx_file.write_all(apptsx_content.as_bytes())?;
+
+  Ok(())
+}
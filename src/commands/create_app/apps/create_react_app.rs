@@ -1,26 +1,37 @@
 use std::io::Write;
 
 use super::AppWithPath;
+use crate::cli::Lang;
 
 pub fn create_react_app(app: AppWithPath) -> anyhow::Result<()> {
   let AppWithPath {
-    app_name, app_path, ..
+    app_name,
+    app_path,
+    options,
+    ..
   } = app;
 
   let app_path = app_path.to_str().unwrap();
 
-  create_package_json(&app_name, app_path)?;
-  create_tsconfig(app_path)?;
-  create_viteconfig(app_path)?;
-  create_indexhtml(&app_name, app_path)?;
-  create_folders(app_path)?;
+  create_package_json(&app_name, app_path, &options.lang)?;
+  match options.lang {
+    Lang::Ts => create_tsconfig(app_path)?,
+    Lang::Js => create_jsconfig(app_path)?,
+  };
+  create_viteconfig(app_path, &options.lang)?;
+  create_indexhtml(&app_name, app_path, &options.lang)?;
+  create_folders(app_path, &options.lang)?;
 
   Ok(())
 }
 
-fn create_package_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+fn create_package_json(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
   let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
   let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let build = match lang {
+    Lang::Ts => "tsc && vite build",
+    Lang::Js => "vite build",
+  };
   let content = format!(
     r#"{{
   "name": "{}",
@@ -29,7 +40,7 @@ fn create_package_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
   "type": "module",
   "scripts": {{
     "dev": "vite",
-    "build": "tsc && vite build",
+    "build": "{build}",
     "preview": "vite preview"
   }}
 }}"#,
@@ -89,8 +100,31 @@ fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
   Ok(())
 }
 
-fn create_viteconfig(app_path: &str) -> anyhow::Result<()> {
-  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.ts"));
+fn create_jsconfig(app_path: &str) -> anyhow::Result<()> {
+  let jsconfig_path = std::path::PathBuf::from(format!("{app_path}/jsconfig.json"));
+  let mut jsconfig_file = std::fs::File::create(jsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "checkJs": false,
+    "jsx": "react-jsx",
+    "lib": ["DOM", "DOM.Iterable", "ESNext"],
+    "module": "ESNext",
+    "moduleResolution": "Node",
+    "resolveJsonModule": true,
+    "skipLibCheck": true,
+    "target": "ESNext"
+  },
+  "include": ["src", "vite.config.js"]
+}"#;
+
+  jsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_viteconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.{ext}"));
   let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
   let content = r#"import { defineConfig } from "vite";
 import react from "@vitejs/plugin-react";
@@ -104,7 +138,8 @@ export default defineConfig({
   Ok(())
 }
 
-fn create_indexhtml(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+fn create_indexhtml(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.jsx_ext();
   let indexhtml_path = std::path::PathBuf::from(format!("{app_path}/index.html"));
   let mut indexhtml_file = std::fs::File::create(indexhtml_path)?;
 
@@ -118,7 +153,7 @@ fn create_indexhtml(app_name: &str, app_path: &str) -> anyhow::Result<()> {
   </head>
   <body>
     <div id="root"></div>
-    <script type="module" src="/src/main.tsx"></script>
+    <script type="module" src="/src/main.{ext}"></script>
   </body>
 </html>"#
   );
@@ -128,43 +163,63 @@ fn create_indexhtml(app_name: &str, app_path: &str) -> anyhow::Result<()> {
   Ok(())
 }
 
-fn create_folders(app_path: &str) -> anyhow::Result<()> {
+fn create_folders(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
   std::fs::create_dir_all(format!("{app_path}/public/"))?;
   std::fs::create_dir_all(format!("{app_path}/src/assets/"))?;
   std::fs::create_dir_all(format!("{app_path}/src/components/"))?;
 
-  let viteenv_path = std::path::PathBuf::from(format!("{app_path}/src/vite-env.d.ts"));
-  let mut viteenv_file = std::fs::File::create(viteenv_path)?;
+  if *lang == Lang::Ts {
+    let viteenv_path = std::path::PathBuf::from(format!("{app_path}/src/vite-env.d.ts"));
+    let mut viteenv_file = std::fs::File::create(viteenv_path)?;
 
-  let viteenv_content = r#"/// <reference types="vite/client" />"#;
+    let viteenv_content = r#"/// <reference types="vite/client" />"#;
 
-  viteenv_file.write_all(viteenv_content.as_bytes())?;
+    viteenv_file.write_all(viteenv_content.as_bytes())?;
+  }
 
-  let maintsx_path = std::path::PathBuf::from(format!("{app_path}/src/main.tsx"));
+  let ext = lang.jsx_ext();
+  let maintsx_path = std::path::PathBuf::from(format!("{app_path}/src/main.{ext}"));
   let mut maintsx_file = std::fs::File::create(maintsx_path)?;
 
-  let maintsx_content = r#"import React from "react";
+  let root_element = match lang {
+    Lang::Ts => r#"document.getElementById("root") as HTMLElement"#,
+    Lang::Js => r#"document.getElementById("root")"#,
+  };
+  let maintsx_content = format!(
+    r#"import React from "react";
 import ReactDOM from "react-dom/client";
 import App from "./App";
 
-ReactDOM.createRoot(document.getElementById("root") as HTMLElement).render(
+ReactDOM.createRoot({root_element}).render(
   <React.StrictMode>
     <App />
   </React.StrictMode>
-);"#;
+);"#
+  );
 
   maintsx_file.write_all(maintsx_content.as_bytes())?;
 
-  let apptsx_path = std::path::PathBuf::from(format!("{app_path}/src/App.tsx"));
+  let apptsx_path = std::path::PathBuf::from(format!("{app_path}/src/App.{ext}"));
   let mut apptsx_file = std::fs::File::create(apptsx_path)?;
 
-  let apptsx_content = r#"import type { FC } from "react";
+  let apptsx_content = match lang {
+    Lang::Ts => {
+      r#"import type { FC } from "react";
 
 const App: FC = () => {
   return <main></main>;
 };
 
-export default App;"#;
+export default App;"#
+    }
+    Lang::Js => {
+      r#"const App = () => {
+  return <main></main>;
+};
+
+export default App;"#
+    }
+  };
 
   apptsx_file.write_all(apptsx_content.as_bytes())?;
 
@@ -0,0 +1,300 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::Lang;
+
+pub fn create_react_router_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options.lang)?;
+  match options.lang {
+    Lang::Ts => create_tsconfig(app_path)?,
+    Lang::Js => create_jsconfig(app_path)?,
+  };
+  create_react_router_config(app_path, &options.lang)?;
+  create_viteconfig(app_path, &options.lang)?;
+  create_folders(&app_name, app_path, &options.lang)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let typecheck = match lang {
+    Lang::Ts => ",\n    \"typecheck\": \"react-router typegen && tsc\"",
+    Lang::Js => "",
+  };
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "react-router dev",
+    "build": "react-router build",
+    "start": "react-router-serve ./build/server/index.js"{typecheck}
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "include": ["**/*", "**/.server/**/*", "**/.client/**/*", ".react-router/types/**/*"],
+  "compilerOptions": {
+    "lib": ["DOM", "DOM.Iterable", "ES2022"],
+    "types": ["node", "vite/client"],
+    "target": "ES2022",
+    "module": "ES2022",
+    "moduleResolution": "bundler",
+    "jsx": "react-jsx",
+    "rootDirs": [".", "./.react-router/types"],
+    "baseUrl": ".",
+    "paths": {
+      "~/*": ["./app/*"]
+    },
+    "esModuleInterop": true,
+    "verbatimModuleSyntax": true,
+    "noEmit": true,
+    "resolveJsonModule": true,
+    "skipLibCheck": true,
+    "strict": true
+  }
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_jsconfig(app_path: &str) -> anyhow::Result<()> {
+  let jsconfig_path = std::path::PathBuf::from(format!("{app_path}/jsconfig.json"));
+  let mut jsconfig_file = std::fs::File::create(jsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "jsx": "react-jsx",
+    "module": "ES2022",
+    "moduleResolution": "bundler",
+    "target": "ES2022",
+    "baseUrl": ".",
+    "paths": {
+      "~/*": ["./app/*"]
+    }
+  }
+}"#;
+
+  jsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_react_router_config(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let config_path = std::path::PathBuf::from(format!("{app_path}/react-router.config.{ext}"));
+  let mut config_file = std::fs::File::create(config_path)?;
+  let content = match lang {
+    Lang::Ts => {
+      r#"import type { Config } from "@react-router/dev/config";
+
+export default {
+  ssr: true,
+} satisfies Config;"#
+    }
+    Lang::Js => {
+      r#"/** @type {import("@react-router/dev/config").Config} */
+export default {
+  ssr: true,
+};"#
+    }
+  };
+
+  config_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_viteconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.{ext}"));
+  let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
+  let content = r#"import { reactRouter } from "@react-router/dev/vite";
+import { defineConfig } from "vite";
+
+export default defineConfig({
+  plugins: [reactRouter()],
+});"#;
+
+  viteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+  std::fs::create_dir_all(format!("{app_path}/app/components/"))?;
+  std::fs::create_dir_all(format!("{app_path}/app/routes/"))?;
+
+  let ts = *lang == Lang::Ts;
+  let ty = |annotation: &'static str| if ts { annotation } else { "" };
+  let ext = lang.jsx_ext();
+
+  let roottsx_path = std::path::PathBuf::from(format!("{app_path}/app/root.{ext}"));
+  let mut roottsx_file = std::fs::File::create(roottsx_path)?;
+
+  let roottsx_content = format!(
+    r#"{}import {{ Links, Meta, Outlet, Scripts, ScrollRestoration }} from "react-router";
+
+export const Layout = ({{ children }}{}) => {{
+  return (
+    <html lang="en">
+      <head>
+        <meta charSet="utf-8" />
+        <meta name="viewport" content="width=device-width, initial-scale=1" />
+        <Meta />
+        <Links />
+      </head>
+      <body>
+        {{children}}
+        <ScrollRestoration />
+        <Scripts />
+      </body>
+    </html>
+  );
+}};
+
+const App = () => {{
+  return <Outlet />;
+}};
+
+export default App;"#,
+    ty("import type { ReactNode } from \"react\";\n"),
+    ty(": { children: ReactNode }")
+  );
+
+  roottsx_file.write_all(roottsx_content.as_bytes())?;
+
+  let routests_path =
+    std::path::PathBuf::from(format!("{app_path}/app/routes.{}", lang.script_ext()));
+  let mut routests_file = std::fs::File::create(routests_path)?;
+
+  let routests_content = match lang {
+    Lang::Ts => {
+      r#"import type { RouteConfig } from "@react-router/dev/routes";
+import { flatRoutes } from "@react-router/fs-routes";
+
+export default flatRoutes() satisfies RouteConfig;"#
+    }
+    Lang::Js => {
+      r#"import { flatRoutes } from "@react-router/fs-routes";
+
+export default flatRoutes();"#
+    }
+  };
+
+  routests_file.write_all(routests_content.as_bytes())?;
+
+  let indextsx_path = std::path::PathBuf::from(format!("{app_path}/app/routes/_index.{ext}"));
+  let mut indextsx_file = std::fs::File::create(indextsx_path)?;
+
+  let indextsx_content = format!(
+    r#"export const meta = () => {{
+  return [{{ title: "{app_name}" }}];
+}};
+
+const Index = () => {{
+  return <main></main>;
+}};
+
+export default Index;"#
+  );
+
+  indextsx_file.write_all(indextsx_content.as_bytes())?;
+
+  let entryserver_path = std::path::PathBuf::from(format!("{app_path}/app/entry.server.{ext}"));
+  let mut entryserver_file = std::fs::File::create(entryserver_path)?;
+
+  let entryserver_content = format!(
+    r#"import {{ PassThrough }} from "node:stream";
+{}import {{ createReadableStreamFromReadable }} from "@react-router/node";
+import {{ ServerRouter }} from "react-router";
+import {{ isbot }} from "isbot";
+import {{ renderToPipeableStream }} from "react-dom/server";
+
+export const streamTimeout = 5_000;
+
+const handleRequest = (
+  request{},
+  responseStatusCode{},
+  responseHeaders{},
+  routerContext{}
+) => {{
+  return new Promise((resolve, reject) => {{
+    let shellRendered = false;
+    const userAgent = request.headers.get("user-agent");
+    const readyOption =
+      (userAgent && isbot(userAgent)) || routerContext.isSpaMode ? "onAllReady" : "onShellReady";
+
+    const {{ pipe, abort }} = renderToPipeableStream(
+      <ServerRouter context={{routerContext}} url={{request.url}} />,
+      {{
+        [readyOption]() {{
+          shellRendered = true;
+          const body = new PassThrough();
+          const stream = createReadableStreamFromReadable(body);
+
+          responseHeaders.set("Content-Type", "text/html");
+
+          resolve(
+            new Response(stream, {{
+              headers: responseHeaders,
+              status: responseStatusCode,
+            }})
+          );
+
+          pipe(body);
+        }},
+        onShellError(error{}) {{
+          reject(error);
+        }},
+        onError(error{}) {{
+          responseStatusCode = 500;
+          if (shellRendered) {{
+            console.error(error);
+          }}
+        }},
+      }}
+    );
+
+    setTimeout(abort, streamTimeout + 1000);
+  }});
+}};
+
+export default handleRequest;"#,
+    ty("import type { EntryContext } from \"react-router\";\n"),
+    ty(": Request"),
+    ty(": number"),
+    ty(": Headers"),
+    ty(": EntryContext"),
+    ty(": unknown"),
+    ty(": unknown")
+  );
+
+  entryserver_file.write_all(entryserver_content.as_bytes())?;
+
+  Ok(())
+}
@@ -0,0 +1,170 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::Lang;
+
+pub fn create_solid_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options.lang)?;
+  create_tsconfig(app_path, &options.lang)?;
+  create_viteconfig(app_path, &options.lang)?;
+  create_indexhtml(&app_name, app_path, &options.lang)?;
+  create_folders(app_path, &options.lang)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let build = match lang {
+    Lang::Ts => "tsc && vite build",
+    Lang::Js => "vite build",
+  };
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "vite",
+    "build": "{build}",
+    "preview": "vite preview"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let config_name = match lang {
+    Lang::Ts => "tsconfig.json",
+    Lang::Js => "jsconfig.json",
+  };
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/{config_name}"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "allowSyntheticDefaultImports": true,
+    "esModuleInterop": true,
+    "isolatedModules": true,
+    "jsx": "preserve",
+    "jsxImportSource": "solid-js",
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "noEmit": true,
+    "skipLibCheck": true,
+    "strict": true,
+    "target": "ESNext",
+    "types": ["vite/client"]
+  },
+  "include": ["src"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_viteconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.{ext}"));
+  let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
+  let content = r#"import { defineConfig } from "vite";
+import solid from "vite-plugin-solid";
+
+export default defineConfig({
+  plugins: [solid()],
+});"#;
+
+  viteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_indexhtml(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.jsx_ext();
+  let indexhtml_path = std::path::PathBuf::from(format!("{app_path}/index.html"));
+  let mut indexhtml_file = std::fs::File::create(indexhtml_path)?;
+
+  let content = format!(
+    r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>{app_name}</title>
+  </head>
+  <body>
+    <div id="root"></div>
+    <script type="module" src="/src/index.{ext}"></script>
+  </body>
+</html>"#
+  );
+
+  indexhtml_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/assets/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/components/"))?;
+
+  let ext = lang.jsx_ext();
+  let indextsx_path = std::path::PathBuf::from(format!("{app_path}/src/index.{ext}"));
+  let mut indextsx_file = std::fs::File::create(indextsx_path)?;
+
+  let non_null = match lang {
+    Lang::Ts => "!",
+    Lang::Js => "",
+  };
+  let indextsx_content = format!(
+    r#"/* @refresh reload */
+import {{ render }} from "solid-js/web";
+import App from "./App";
+
+render(() => <App />, document.getElementById("root"){non_null});"#
+  );
+
+  indextsx_file.write_all(indextsx_content.as_bytes())?;
+
+  let apptsx_path = std::path::PathBuf::from(format!("{app_path}/src/App.{ext}"));
+  let mut apptsx_file = std::fs::File::create(apptsx_path)?;
+
+  let apptsx_content = match lang {
+    Lang::Ts => {
+      r#"import type { Component } from "solid-js";
+
+const App: Component = () => {
+  return <main></main>;
+};
+
+export default App;"#
+    }
+    Lang::Js => {
+      r#"const App = () => {
+  return <main></main>;
+};
+
+export default App;"#
+    }
+  };
+
+  apptsx_file.write_all(apptsx_content.as_bytes())?;
+
+  Ok(())
+}
@@ -0,0 +1,190 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::Lang;
+
+pub fn create_solidstart_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path)?;
+  create_tsconfig(app_path, &options.lang)?;
+  create_appconfig(app_path, &options.lang)?;
+  create_folders(&app_name, app_path, &options.lang)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "vinxi dev",
+    "build": "vinxi build",
+    "start": "vinxi start"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let config_name = match lang {
+    Lang::Ts => "tsconfig.json",
+    Lang::Js => "jsconfig.json",
+  };
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/{config_name}"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "allowJs": true,
+    "allowSyntheticDefaultImports": true,
+    "esModuleInterop": true,
+    "isolatedModules": true,
+    "jsx": "preserve",
+    "jsxImportSource": "solid-js",
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "noEmit": true,
+    "strict": true,
+    "target": "ESNext",
+    "types": ["vinxi/types/client"],
+    "paths": {
+      "~/*": ["./src/*"]
+    }
+  }
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_appconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let appconfig_path = std::path::PathBuf::from(format!("{app_path}/app.config.{ext}"));
+  let mut appconfig_file = std::fs::File::create(appconfig_path)?;
+  let content = r#"import { defineConfig } from "@solidjs/start/config";
+
+export default defineConfig({});"#;
+
+  appconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/components/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/routes/"))?;
+
+  let ext = lang.jsx_ext();
+  let non_null = match lang {
+    Lang::Ts => "!",
+    Lang::Js => "",
+  };
+
+  if *lang == Lang::Ts {
+    let globaldts_path = std::path::PathBuf::from(format!("{app_path}/src/global.d.ts"));
+    let mut globaldts_file = std::fs::File::create(globaldts_path)?;
+
+    let globaldts_content = r#"/// <reference types="@solidjs/start/env" />"#;
+
+    globaldts_file.write_all(globaldts_content.as_bytes())?;
+  }
+
+  let apptsx_path = std::path::PathBuf::from(format!("{app_path}/src/app.{ext}"));
+  let mut apptsx_file = std::fs::File::create(apptsx_path)?;
+
+  let apptsx_content = format!(
+    r#"import {{ MetaProvider, Title }} from "@solidjs/meta";
+import {{ Router }} from "@solidjs/router";
+import {{ FileRoutes }} from "@solidjs/start/router";
+import {{ Suspense }} from "solid-js";
+
+const App = () => {{
+  return (
+    <Router
+      root={{(props) => (
+        <MetaProvider>
+          <Title>{app_name}</Title>
+          <Suspense>{{props.children}}</Suspense>
+        </MetaProvider>
+      )}}
+    >
+      <FileRoutes />
+    </Router>
+  );
+}};
+
+export default App;"#
+  );
+
+  apptsx_file.write_all(apptsx_content.as_bytes())?;
+
+  let entryclient_path = std::path::PathBuf::from(format!("{app_path}/src/entry-client.{ext}"));
+  let mut entryclient_file = std::fs::File::create(entryclient_path)?;
+
+  let entryclient_content = format!(
+    r#"// @refresh reload
+import {{ mount, StartClient }} from "@solidjs/start/client";
+
+mount(() => <StartClient />, document.getElementById("app"){non_null});"#
+  );
+
+  entryclient_file.write_all(entryclient_content.as_bytes())?;
+
+  let entryserver_path = std::path::PathBuf::from(format!("{app_path}/src/entry-server.{ext}"));
+  let mut entryserver_file = std::fs::File::create(entryserver_path)?;
+
+  let entryserver_content = r#"// @refresh reload
+import { createHandler, StartServer } from "@solidjs/start/server";
+
+export default createHandler(() => (
+  <StartServer
+    document={({ assets, children, scripts }) => (
+      <html lang="en">
+        <head>
+          <meta charset="utf-8" />
+          <meta name="viewport" content="width=device-width, initial-scale=1" />
+          {assets}
+        </head>
+        <body>
+          <div id="app">{children}</div>
+          {scripts}
+        </body>
+      </html>
+    )}
+  />
+));"#;
+
+  entryserver_file.write_all(entryserver_content.as_bytes())?;
+
+  let indextsx_path = std::path::PathBuf::from(format!("{app_path}/src/routes/index.{ext}"));
+  let mut indextsx_file = std::fs::File::create(indextsx_path)?;
+
+  let indextsx_content = r#"const Home = () => {
+  return <main></main>;
+};
+
+export default Home;"#;
+
+  indextsx_file.write_all(indextsx_content.as_bytes())?;
+
+  Ok(())
+}
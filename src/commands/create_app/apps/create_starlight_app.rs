@@ -0,0 +1,127 @@
+use std::io::Write;
+
+use super::{create_astro_app, AppWithPath};
+use crate::cli::Lang;
+
+pub fn create_starlight_app(app: AppWithPath) -> anyhow::Result<()> {
+  let app_name = app.app_name.clone();
+  let app_path = app.app_path.clone();
+  let lang = app.options.lang.clone();
+
+  create_astro_app(app)?;
+
+  let app_path = app_path.to_str().unwrap();
+
+  // Starlight renders every page from `src/content/docs/`, including the index.
+  std::fs::remove_dir_all(format!("{app_path}/src/pages/"))?;
+  std::fs::remove_dir_all(format!("{app_path}/src/layouts/"))?;
+
+  create_astroconfig(&app_name, app_path)?;
+  create_content_config(app_path, &lang)?;
+  create_docs(&app_name, app_path)?;
+
+  Ok(())
+}
+
+fn create_astroconfig(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let astroconfig_path = std::path::PathBuf::from(format!("{app_path}/astro.config.mjs"));
+  let mut astroconfig_file = std::fs::File::create(astroconfig_path)?;
+  let content = format!(
+    r#"import {{ defineConfig }} from "astro/config";
+import starlight from "@astrojs/starlight";
+
+export default defineConfig({{
+  integrations: [
+    starlight({{
+      title: "{app_name}",
+      sidebar: [
+        {{
+          label: "Guides",
+          items: [{{ label: "Getting started", slug: "guides/getting-started" }}],
+        }},
+        {{
+          label: "Reference",
+          autogenerate: {{ directory: "reference" }},
+        }},
+      ],
+    }}),
+  ],
+}});"#
+  );
+
+  astroconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_content_config(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let content_config_path = std::path::PathBuf::from(format!(
+    "{app_path}/src/content.config.{}",
+    lang.script_ext()
+  ));
+  let mut content_config_file = std::fs::File::create(content_config_path)?;
+  let content = r#"import { defineCollection } from "astro:content";
+import { docsLoader } from "@astrojs/starlight/loaders";
+import { docsSchema } from "@astrojs/starlight/schema";
+
+export const collections = {
+  docs: defineCollection({ loader: docsLoader(), schema: docsSchema() }),
+};"#;
+
+  content_config_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_docs(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src/content/docs/guides/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/content/docs/reference/"))?;
+
+  let indexmdx_path = std::path::PathBuf::from(format!("{app_path}/src/content/docs/index.mdx"));
+  let mut indexmdx_file = std::fs::File::create(indexmdx_path)?;
+
+  let indexmdx_content = format!(
+    r#"---
+title: {app_name}
+description: Documentation for {app_name}.
+template: splash
+hero:
+  tagline: Documentation for {app_name}.
+  actions:
+    - text: Get started
+      link: /guides/getting-started/
+      icon: right-arrow
+---"#
+  );
+
+  indexmdx_file.write_all(indexmdx_content.as_bytes())?;
+
+  let guidemd_path = std::path::PathBuf::from(format!(
+    "{app_path}/src/content/docs/guides/getting-started.md"
+  ));
+  let mut guidemd_file = std::fs::File::create(guidemd_path)?;
+
+  let guidemd_content = r#"---
+title: Getting started
+description: Install and set up the project.
+---
+
+Write your first guide here."#;
+
+  guidemd_file.write_all(guidemd_content.as_bytes())?;
+
+  let referencemd_path =
+    std::path::PathBuf::from(format!("{app_path}/src/content/docs/reference/overview.md"));
+  let mut referencemd_file = std::fs::File::create(referencemd_path)?;
+
+  let referencemd_content = r#"---
+title: Overview
+description: An overview of the API.
+---
+
+Pages in this folder are added to the sidebar automatically."#;
+
+  referencemd_file.write_all(referencemd_content.as_bytes())?;
+
+  Ok(())
+}
@@ -0,0 +1,208 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::{Lang, SvelteAdapter};
+
+pub fn create_sveltekit_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options.lang)?;
+  create_tsconfig(app_path, &options.lang)?;
+  create_svelteconfig(app_path, &options.adapter)?;
+  create_viteconfig(app_path, &options.lang)?;
+  create_folders(&app_name, app_path, &options.lang, &options.adapter)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let config_name = match lang {
+    Lang::Ts => "tsconfig.json",
+    Lang::Js => "jsconfig.json",
+  };
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "vite dev",
+    "build": "vite build",
+    "preview": "vite preview",
+    "prepare": "svelte-kit sync || echo ''",
+    "check": "svelte-kit sync && svelte-check --tsconfig ./{config_name}",
+    "check:watch": "svelte-kit sync && svelte-check --tsconfig ./{config_name} --watch"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let config_name = match lang {
+    Lang::Ts => "tsconfig.json",
+    Lang::Js => "jsconfig.json",
+  };
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/{config_name}"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "extends": "./.svelte-kit/tsconfig.json",
+  "compilerOptions": {
+    "allowJs": true,
+    "checkJs": true,
+    "esModuleInterop": true,
+    "forceConsistentCasingInFileNames": true,
+    "moduleResolution": "bundler",
+    "resolveJsonModule": true,
+    "skipLibCheck": true,
+    "sourceMap": true,
+    "strict": true
+  }
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_svelteconfig(app_path: &str, adapter: &SvelteAdapter) -> anyhow::Result<()> {
+  let svelteconfig_path = std::path::PathBuf::from(format!("{app_path}/svelte.config.js"));
+  let mut svelteconfig_file = std::fs::File::create(svelteconfig_path)?;
+  let adapter_package = adapter.package();
+  let content = format!(
+    r#"import adapter from "{adapter_package}";
+import {{ vitePreprocess }} from "@sveltejs/vite-plugin-svelte";
+
+/** @type {{import('@sveltejs/kit').Config}} */
+const config = {{
+  preprocess: vitePreprocess(),
+  kit: {{
+    adapter: adapter(),
+  }},
+}};
+
+export default config;"#
+  );
+
+  svelteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_viteconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.{ext}"));
+  let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
+  let content = r#"import { sveltekit } from "@sveltejs/kit/vite";
+import { defineConfig } from "vite";
+
+export default defineConfig({
+  plugins: [sveltekit()],
+});"#;
+
+  viteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(
+  app_name: &str,
+  app_path: &str,
+  lang: &Lang,
+  adapter: &SvelteAdapter,
+) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/static/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/lib/components/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/routes/"))?;
+
+  let apphtml_path = std::path::PathBuf::from(format!("{app_path}/src/app.html"));
+  let mut apphtml_file = std::fs::File::create(apphtml_path)?;
+
+  let apphtml_content = r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    %sveltekit.head%
+  </head>
+  <body data-sveltekit-preload-data="hover">
+    <div style="display: contents">%sveltekit.body%</div>
+  </body>
+</html>"#;
+
+  apphtml_file.write_all(apphtml_content.as_bytes())?;
+
+  if *lang == Lang::Ts {
+    let appdts_path = std::path::PathBuf::from(format!("{app_path}/src/app.d.ts"));
+    let mut appdts_file = std::fs::File::create(appdts_path)?;
+
+    let appdts_content = r#"declare global {
+  namespace App {
+    // interface Error {}
+    // interface Locals {}
+    // interface PageData {}
+    // interface PageState {}
+    // interface Platform {}
+  }
+}
+
+export {};"#;
+
+    appdts_file.write_all(appdts_content.as_bytes())?;
+  }
+
+  let script_lang = match lang {
+    Lang::Ts => r#" lang="ts""#,
+    Lang::Js => "",
+  };
+  let layoutsvelte_path = std::path::PathBuf::from(format!("{app_path}/src/routes/+layout.svelte"));
+  let mut layoutsvelte_file = std::fs::File::create(layoutsvelte_path)?;
+
+  let layoutsvelte_content = format!(
+    r#"<script{script_lang}>
+  let {{ children }} = $props();
+</script>
+
+<main>
+  {{@render children()}}
+</main>"#
+  );
+
+  layoutsvelte_file.write_all(layoutsvelte_content.as_bytes())?;
+
+  if *adapter == SvelteAdapter::Static {
+    let ext = lang.script_ext();
+    let layoutts_path = std::path::PathBuf::from(format!("{app_path}/src/routes/+layout.{ext}"));
+    let mut layoutts_file = std::fs::File::create(layoutts_path)?;
+
+    let layoutts_content = r#"export const prerender = true;"#;
+
+    layoutts_file.write_all(layoutts_content.as_bytes())?;
+  }
+
+  let pagesvelte_path = std::path::PathBuf::from(format!("{app_path}/src/routes/+page.svelte"));
+  let mut pagesvelte_file = std::fs::File::create(pagesvelte_path)?;
+
+  let pagesvelte_content = format!(
+    r#"<svelte:head>
+  <title>{app_name}</title>
+</svelte:head>"#
+  );
+
+  pagesvelte_file.write_all(pagesvelte_content.as_bytes())?;
+
+  Ok(())
+}
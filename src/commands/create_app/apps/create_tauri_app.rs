@@ -0,0 +1,214 @@
+use std::io::Write;
+
+use super::{create_react_app, create_sveltekit_app, create_vue_app, AppWithPath};
+use crate::cli::{AppType, PackageManager};
+
+/// Placeholder icons, since tauri-build and `generate_context!` fail without
+/// them. `tauri icon <image>` replaces them with real ones.
+const ICONS: [(&str, &[u8]); 4] = [
+  (
+    "32x32.png",
+    include_bytes!("../../../../assets/tauri/icons/32x32.png"),
+  ),
+  (
+    "128x128.png",
+    include_bytes!("../../../../assets/tauri/icons/128x128.png"),
+  ),
+  (
+    "icon.png",
+    include_bytes!("../../../../assets/tauri/icons/icon.png"),
+  ),
+  (
+    "icon.ico",
+    include_bytes!("../../../../assets/tauri/icons/icon.ico"),
+  ),
+];
+
+pub fn create_tauri_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let (frontend_type, frontend_options) = options.tauri_frontend();
+  let frontend_dist = match frontend_type {
+    AppType::SvelteKit => "../build",
+    _ => "../dist",
+  };
+  let frontend = AppWithPath {
+    app_type: frontend_type.clone(),
+    app_name: app_name.clone(),
+    app_path: app_path.clone(),
+    options: frontend_options,
+  };
+
+  match frontend_type {
+    AppType::Vue => create_vue_app(frontend)?,
+    AppType::SvelteKit => create_sveltekit_app(frontend)?,
+    _ => create_react_app(frontend)?,
+  };
+
+  let app_path = app_path.to_str().unwrap();
+
+  add_tauri_script(app_path)?;
+  create_cargo_toml(&app_name, app_path)?;
+  create_tauri_conf(&app_name, app_path, frontend_dist, &options.package_manager)?;
+  create_capabilities(app_path)?;
+  create_icons(app_path)?;
+  create_main_rs(app_path)?;
+
+  Ok(())
+}
+
+fn add_tauri_script(app_path: &str) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let content = std::fs::read_to_string(&package_json_path)?.replacen(
+    "\"scripts\": {",
+    "\"scripts\": {\n    \"tauri\": \"tauri\",",
+    1,
+  );
+
+  std::fs::write(package_json_path, content)?;
+
+  Ok(())
+}
+
+fn create_cargo_toml(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src-tauri/src/"))?;
+
+  let cargo_toml_path = std::path::PathBuf::from(format!("{app_path}/src-tauri/Cargo.toml"));
+  let mut cargo_toml_file = std::fs::File::create(cargo_toml_path)?;
+  let content = format!(
+    r#"[package]
+name = "{app_name}"
+version = "0.1.0"
+edition = "2021"
+
+[build-dependencies]
+tauri-build = {{ version = "2", features = [] }}
+
+[dependencies]
+tauri = {{ version = "2", features = [] }}
+serde = {{ version = "1", features = ["derive"] }}
+serde_json = "1""#
+  );
+
+  cargo_toml_file.write_all(content.as_bytes())?;
+
+  let build_rs_path = std::path::PathBuf::from(format!("{app_path}/src-tauri/build.rs"));
+  let mut build_rs_file = std::fs::File::create(build_rs_path)?;
+  let content = r#"fn main() {
+    tauri_build::build()
+}"#;
+
+  build_rs_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tauri_conf(
+  app_name: &str,
+  app_path: &str,
+  frontend_dist: &str,
+  package_manager: &PackageManager,
+) -> anyhow::Result<()> {
+  let tauri_conf_path = std::path::PathBuf::from(format!("{app_path}/src-tauri/tauri.conf.json"));
+  let mut tauri_conf_file = std::fs::File::create(tauri_conf_path)?;
+  let run_dev = package_manager.run("dev");
+  let run_build = package_manager.run("build");
+  let content = format!(
+    r#"{{
+  "$schema": "https://schema.tauri.app/config/2",
+  "productName": "{app_name}",
+  "version": "0.1.0",
+  "identifier": "com.example.{app_name}",
+  "build": {{
+    "beforeDevCommand": "{run_dev}",
+    "devUrl": "http://localhost:5173",
+    "beforeBuildCommand": "{run_build}",
+    "frontendDist": "{frontend_dist}"
+  }},
+  "app": {{
+    "windows": [
+      {{
+        "title": "{app_name}",
+        "width": 800,
+        "height": 600
+      }}
+    ],
+    "security": {{
+      "csp": null
+    }}
+  }},
+  "bundle": {{
+    "active": true,
+    "targets": "all",
+    "icon": [
+      "icons/32x32.png",
+      "icons/128x128.png",
+      "icons/icon.png",
+      "icons/icon.ico"
+    ]
+  }}
+}}"#
+  );
+
+  tauri_conf_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_capabilities(app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src-tauri/capabilities/"))?;
+
+  let capability_path =
+    std::path::PathBuf::from(format!("{app_path}/src-tauri/capabilities/default.json"));
+  let mut capability_file = std::fs::File::create(capability_path)?;
+  let content = r#"{
+  "$schema": "../gen/schemas/desktop-schema.json",
+  "identifier": "default",
+  "description": "Capability for the main window",
+  "windows": ["main"],
+  "permissions": ["core:default"]
+}"#;
+
+  capability_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_icons(app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/src-tauri/icons/"))?;
+
+  for (name, content) in ICONS {
+    std::fs::write(format!("{app_path}/src-tauri/icons/{name}"), content)?;
+  }
+
+  Ok(())
+}
+
+fn create_main_rs(app_path: &str) -> anyhow::Result<()> {
+  let main_rs_path = std::path::PathBuf::from(format!("{app_path}/src-tauri/src/main.rs"));
+  let mut main_rs_file = std::fs::File::create(main_rs_path)?;
+
+  let main_rs_content = r#"// Prevents an additional console window on Windows in release builds.
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+#[tauri::command]
+fn greet(name: &str) -> String {
+    format!("Hello, {name}!")
+}
+
+fn main() {
+    tauri::Builder::default()
+        .invoke_handler(tauri::generate_handler![greet])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}"#;
+
+  main_rs_file.write_all(main_rs_content.as_bytes())?;
+
+  Ok(())
+}
@@ -0,0 +1,110 @@
+use std::io::Write;
+
+use super::AppWithPath;
+
+pub fn create_vitepress_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path)?;
+  create_vitepress_config(&app_name, app_path, options.lang.script_ext())?;
+  create_docs(&app_name, app_path)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "vitepress dev docs",
+    "build": "vitepress build docs",
+    "preview": "vitepress preview docs",
+    "docs:dev": "vitepress dev docs",
+    "docs:build": "vitepress build docs",
+    "docs:preview": "vitepress preview docs"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_vitepress_config(app_name: &str, app_path: &str, ext: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/docs/.vitepress/"))?;
+
+  let config_path = std::path::PathBuf::from(format!("{app_path}/docs/.vitepress/config.{ext}"));
+  let mut config_file = std::fs::File::create(config_path)?;
+  let content = format!(
+    r#"import {{ defineConfig }} from "vitepress";
+
+export default defineConfig({{
+  title: "{app_name}",
+  description: "Documentation for {app_name}",
+  themeConfig: {{
+    nav: [
+      {{ text: "Home", link: "/" }},
+      {{ text: "Guide", link: "/guide/getting-started" }},
+    ],
+    sidebar: [
+      {{
+        text: "Guide",
+        items: [{{ text: "Getting started", link: "/guide/getting-started" }}],
+      }},
+    ],
+  }},
+}});"#
+  );
+
+  config_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_docs(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/docs/guide/"))?;
+
+  let indexmd_path = std::path::PathBuf::from(format!("{app_path}/docs/index.md"));
+  let mut indexmd_file = std::fs::File::create(indexmd_path)?;
+
+  let indexmd_content = format!(
+    r#"---
+layout: home
+
+hero:
+  name: {app_name}
+  tagline: Documentation for {app_name}.
+  actions:
+    - theme: brand
+      text: Get started
+      link: /guide/getting-started
+---"#
+  );
+
+  indexmd_file.write_all(indexmd_content.as_bytes())?;
+
+  let guidemd_path = std::path::PathBuf::from(format!("{app_path}/docs/guide/getting-started.md"));
+  let mut guidemd_file = std::fs::File::create(guidemd_path)?;
+
+  let guidemd_content = r#"# Getting started
+
+Write your first guide here."#;
+
+  guidemd_file.write_all(guidemd_content.as_bytes())?;
+
+  Ok(())
+}
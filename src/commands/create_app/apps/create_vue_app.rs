@@ -0,0 +1,202 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::Lang;
+
+pub fn create_vue_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options.lang)?;
+  match options.lang {
+    Lang::Ts => create_tsconfig(app_path)?,
+    Lang::Js => create_jsconfig(app_path)?,
+  };
+  create_viteconfig(app_path, &options.lang)?;
+  create_indexhtml(&app_name, app_path, &options.lang)?;
+  create_folders(app_path, &options.lang)?;
+
+  Ok(())
+}
+
+fn create_package_json(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let build = match lang {
+    Lang::Ts => "vue-tsc --noEmit && vite build",
+    Lang::Js => "vite build",
+  };
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "dev": "vite",
+    "build": "{build}",
+    "preview": "vite preview"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "esModuleInterop": true,
+    "isolatedModules": true,
+    "jsx": "preserve",
+    "lib": ["DOM", "ESNext"],
+    "module": "ESNext",
+    "moduleResolution": "Node",
+    "noEmit": true,
+    "resolveJsonModule": true,
+    "skipLibCheck": true,
+    "strict": true,
+    "target": "ESNext",
+    "useDefineForClassFields": true
+  },
+  "include": ["src/**/*.ts", "src/**/*.d.ts", "src/**/*.tsx", "src/**/*.vue"],
+  "references": [
+    {
+      "path": "./tsconfig.node.json"
+    }
+  ]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  let tsconfig_node_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.node.json"));
+  let mut tsconfig_node_file = std::fs::File::create(tsconfig_node_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "allowSyntheticDefaultImports": true,
+    "composite": true,
+    "module": "ESNext",
+    "moduleResolution": "Node"
+  },
+  "include": ["vite.config.ts"]
+}"#;
+
+  tsconfig_node_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_jsconfig(app_path: &str) -> anyhow::Result<()> {
+  let jsconfig_path = std::path::PathBuf::from(format!("{app_path}/jsconfig.json"));
+  let mut jsconfig_file = std::fs::File::create(jsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "checkJs": false,
+    "lib": ["DOM", "ESNext"],
+    "module": "ESNext",
+    "moduleResolution": "Node",
+    "target": "ESNext"
+  },
+  "include": ["src/**/*.js", "src/**/*.vue", "vite.config.js"]
+}"#;
+
+  jsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_viteconfig(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.{ext}"));
+  let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
+  let content = r#"import { defineConfig } from "vite";
+import vue from "@vitejs/plugin-vue";
+
+export default defineConfig({
+  plugins: [vue()],
+});"#;
+
+  viteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_indexhtml(app_name: &str, app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  let ext = lang.script_ext();
+  let indexhtml_path = std::path::PathBuf::from(format!("{app_path}/index.html"));
+  let mut indexhtml_file = std::fs::File::create(indexhtml_path)?;
+
+  let content = format!(
+    r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>{app_name}</title>
+  </head>
+  <body>
+    <div id="app"></div>
+    <script type="module" src="/src/main.{ext}"></script>
+  </body>
+</html>"#
+  );
+
+  indexhtml_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str, lang: &Lang) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/assets/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/components/"))?;
+
+  if *lang == Lang::Ts {
+    let envdts_path = std::path::PathBuf::from(format!("{app_path}/src/env.d.ts"));
+    let mut envdts_file = std::fs::File::create(envdts_path)?;
+
+    let envdts_content = r#"/// <reference types="vite/client" />"#;
+
+    envdts_file.write_all(envdts_content.as_bytes())?;
+  }
+
+  let ext = lang.script_ext();
+  let maints_path = std::path::PathBuf::from(format!("{app_path}/src/main.{ext}"));
+  let mut maints_file = std::fs::File::create(maints_path)?;
+
+  let maints_content = r##"import { createApp } from "vue";
+import App from "./App.vue";
+
+createApp(App).mount("#app");"##;
+
+  maints_file.write_all(maints_content.as_bytes())?;
+
+  let appvue_path = std::path::PathBuf::from(format!("{app_path}/src/App.vue"));
+  let mut appvue_file = std::fs::File::create(appvue_path)?;
+
+  let script_lang = match lang {
+    Lang::Ts => r#" lang="ts""#,
+    Lang::Js => "",
+  };
+  let appvue_content = format!(
+    r#"<script setup{script_lang}></script>
+
+<template>
+  <main></main>
+</template>"#
+  );
+
+  appvue_file.write_all(appvue_content.as_bytes())?;
+
+  Ok(())
+}
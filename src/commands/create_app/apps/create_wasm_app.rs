@@ -0,0 +1,178 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::PackageManager;
+
+pub fn create_wasm_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+  let crate_name = format!("{}_wasm", app_name.replace('-', "_"));
+
+  create_package_json(&app_name, app_path, &options.package_manager)?;
+  create_tsconfig(app_path)?;
+  create_viteconfig(app_path)?;
+  create_indexhtml(&app_name, app_path)?;
+  create_crate(&crate_name, app_path)?;
+  create_folders(&crate_name, app_path)?;
+
+  Ok(())
+}
+
+fn create_package_json(
+  app_name: &str,
+  app_path: &str,
+  package_manager: &PackageManager,
+) -> anyhow::Result<()> {
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let build_wasm = package_manager.run("build:wasm");
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",
+  "type": "module",
+  "scripts": {{
+    "build:wasm": "wasm-pack build wasm --target bundler",
+    "dev": "{build_wasm} && vite",
+    "build": "{build_wasm} && tsc && vite build",
+    "preview": "vite preview"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "isolatedModules": true,
+    "lib": ["DOM", "DOM.Iterable", "ES2022"],
+    "module": "ESNext",
+    "moduleResolution": "bundler",
+    "noEmit": true,
+    "skipLibCheck": true,
+    "strict": true,
+    "target": "ES2022",
+    "types": ["vite/client"]
+  },
+  "include": ["src"]
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_viteconfig(app_path: &str) -> anyhow::Result<()> {
+  let viteconfig_path = std::path::PathBuf::from(format!("{app_path}/vite.config.ts"));
+  let mut viteconfig_file = std::fs::File::create(viteconfig_path)?;
+  let content = r#"import { defineConfig } from "vite";
+import wasm from "vite-plugin-wasm";
+import topLevelAwait from "vite-plugin-top-level-await";
+
+export default defineConfig({
+  plugins: [wasm(), topLevelAwait()],
+});"#;
+
+  viteconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_indexhtml(app_name: &str, app_path: &str) -> anyhow::Result<()> {
+  let indexhtml_path = std::path::PathBuf::from(format!("{app_path}/index.html"));
+  let mut indexhtml_file = std::fs::File::create(indexhtml_path)?;
+
+  let content = format!(
+    r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1.0" />
+    <title>{app_name}</title>
+  </head>
+  <body>
+    <div id="app"></div>
+    <script type="module" src="/src/main.ts"></script>
+  </body>
+</html>"#
+  );
+
+  indexhtml_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_crate(crate_name: &str, app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/wasm/src/"))?;
+
+  let cargo_toml_path = std::path::PathBuf::from(format!("{app_path}/wasm/Cargo.toml"));
+  let mut cargo_toml_file = std::fs::File::create(cargo_toml_path)?;
+  let content = format!(
+    r#"[package]
+name = "{crate_name}"
+version = "0.1.0"
+edition = "2021"
+
+[lib]
+crate-type = ["cdylib", "rlib"]
+
+[dependencies]
+wasm-bindgen = "0.2"
+
+[profile.release]
+opt-level = "s""#
+  );
+
+  cargo_toml_file.write_all(content.as_bytes())?;
+
+  let lib_rs_path = std::path::PathBuf::from(format!("{app_path}/wasm/src/lib.rs"));
+  let mut lib_rs_file = std::fs::File::create(lib_rs_path)?;
+  let content = r#"use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub fn greet(name: &str) -> String {
+    format!("Hello, {name}!")
+}"#;
+
+  lib_rs_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(crate_name: &str, app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/public/"))?;
+  std::fs::create_dir_all(format!("{app_path}/src/"))?;
+
+  let viteenv_path = std::path::PathBuf::from(format!("{app_path}/src/vite-env.d.ts"));
+  let mut viteenv_file = std::fs::File::create(viteenv_path)?;
+
+  let viteenv_content = r#"/// <reference types="vite/client" />"#;
+
+  viteenv_file.write_all(viteenv_content.as_bytes())?;
+
+  let maints_path = std::path::PathBuf::from(format!("{app_path}/src/main.ts"));
+  let mut maints_file = std::fs::File::create(maints_path)?;
+
+  let maints_content = format!(
+    r##"import {{ greet }} from "../wasm/pkg/{crate_name}";
+
+document.querySelector<HTMLDivElement>("#app")!.textContent = greet("wasm");"##
+  );
+
+  maints_file.write_all(maints_content.as_bytes())?;
+
+  Ok(())
+}
@@ -0,0 +1,173 @@
+use std::io::Write;
+
+use super::AppWithPath;
+use crate::cli::{PackageManager, Pipeline, TemplateOptions};
+
+pub fn create_workspace_app(app: AppWithPath) -> anyhow::Result<()> {
+  let AppWithPath {
+    app_name,
+    app_path,
+    options,
+    ..
+  } = app;
+
+  let app_path = app_path.to_str().unwrap();
+
+  create_package_json(&app_name, app_path, &options)?;
+  if options.package_manager == PackageManager::Pnpm {
+    create_pnpm_workspace(app_path)?;
+  }
+  match options.pipeline {
+    Pipeline::None => {}
+    Pipeline::Turbo => create_turbo_json(app_path)?,
+    Pipeline::Nx => create_nx_json(app_path)?,
+  };
+  create_tsconfig(app_path)?;
+  create_folders(app_path)?;
+
+  Ok(())
+}
+
+fn create_package_json(
+  app_name: &str,
+  app_path: &str,
+  options: &TemplateOptions,
+) -> anyhow::Result<()> {
+  let package_manager = &options.package_manager;
+  let package_json_path = std::path::PathBuf::from(format!("{app_path}/package.json"));
+  let mut package_json_file = std::fs::File::create(package_json_path)?;
+  let workspaces = match package_manager {
+    PackageManager::Pnpm => "",
+    PackageManager::Npm | PackageManager::Yarn => {
+      r#"
+  "workspaces": ["apps/*", "packages/*"],"#
+    }
+  };
+  let [dev, build, lint, test] =
+    ["dev", "build", "lint", "test"].map(|task| match options.pipeline {
+      Pipeline::None => run_in_members(package_manager, task, task == "dev"),
+      Pipeline::Turbo => format!("turbo run {task}"),
+      Pipeline::Nx => format!("nx run-many -t {task}"),
+    });
+  let content = format!(
+    r#"{{
+  "name": "{app_name}",
+  "private": true,
+  "version": "0.1.0",{workspaces}
+  "scripts": {{
+    "dev": "{dev}",
+    "build": "{build}",
+    "lint": "{lint}",
+    "test": "{test}"
+  }}
+}}"#
+  );
+
+  package_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+/// Runs a script in every workspace member that defines it, in parallel for
+/// long-running ones like `dev`.
+fn run_in_members(package_manager: &PackageManager, script: &str, parallel: bool) -> String {
+  match (package_manager, parallel) {
+    (PackageManager::Pnpm, true) => format!("pnpm -r --parallel {script}"),
+    (PackageManager::Pnpm, false) => format!("pnpm -r {script}"),
+    (PackageManager::Npm, _) => format!("npm run {script} --workspaces --if-present"),
+    (PackageManager::Yarn, true) => format!("yarn workspaces foreach -Api run {script}"),
+    (PackageManager::Yarn, false) => format!("yarn workspaces foreach -At run {script}"),
+  }
+}
+
+fn create_pnpm_workspace(app_path: &str) -> anyhow::Result<()> {
+  let pnpm_workspace_path = std::path::PathBuf::from(format!("{app_path}/pnpm-workspace.yaml"));
+  let mut pnpm_workspace_file = std::fs::File::create(pnpm_workspace_path)?;
+  let content = r#"packages:
+  - "apps/*"
+  - "packages/*""#;
+
+  pnpm_workspace_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_turbo_json(app_path: &str) -> anyhow::Result<()> {
+  let turbo_json_path = std::path::PathBuf::from(format!("{app_path}/turbo.json"));
+  let mut turbo_json_file = std::fs::File::create(turbo_json_path)?;
+  let content = r#"{
+  "$schema": "https://turbo.build/schema.json",
+  "tasks": {
+    "build": {
+      "dependsOn": ["^build"],
+      "outputs": ["dist/**"]
+    },
+    "dev": {
+      "cache": false,
+      "persistent": true
+    },
+    "lint": {
+      "dependsOn": ["^lint"]
+    },
+    "test": {
+      "dependsOn": ["^build"]
+    }
+  }
+}"#;
+
+  turbo_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_nx_json(app_path: &str) -> anyhow::Result<()> {
+  let nx_json_path = std::path::PathBuf::from(format!("{app_path}/nx.json"));
+  let mut nx_json_file = std::fs::File::create(nx_json_path)?;
+  let content = r#"{
+  "$schema": "./node_modules/nx/schemas/nx-schema.json",
+  "targetDefaults": {
+    "build": {
+      "dependsOn": ["^build"],
+      "outputs": ["{projectRoot}/dist"],
+      "cache": true
+    },
+    "lint": {
+      "cache": true
+    },
+    "test": {
+      "dependsOn": ["^build"],
+      "cache": true
+    }
+  }
+}"#;
+
+  nx_json_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_tsconfig(app_path: &str) -> anyhow::Result<()> {
+  let tsconfig_path = std::path::PathBuf::from(format!("{app_path}/tsconfig.base.json"));
+  let mut tsconfig_file = std::fs::File::create(tsconfig_path)?;
+  let content = r#"{
+  "compilerOptions": {
+    "esModuleInterop": true,
+    "forceConsistentCasingInFileNames": true,
+    "isolatedModules": true,
+    "resolveJsonModule": true,
+    "skipLibCheck": true,
+    "strict": true
+  }
+}"#;
+
+  tsconfig_file.write_all(content.as_bytes())?;
+
+  Ok(())
+}
+
+fn create_folders(app_path: &str) -> anyhow::Result<()> {
+  std::fs::create_dir_all(format!("{app_path}/apps/"))?;
+  std::fs::create_dir_all(format!("{app_path}/packages/"))?;
+
+  Ok(())
+}
@@ -1,4 +1,6 @@
+pub mod add_eslint;
 pub mod add_git;
 pub mod add_tailwind;
+pub use add_eslint::add_eslint;
 pub use add_git::add_git;
 pub use add_tailwind::add_tailwind;
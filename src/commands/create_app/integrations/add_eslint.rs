@@ -0,0 +1,287 @@
+use crate::cli::{AppType, Lang, PackageKind, TemplateOptions};
+
+/// The framework specific part of an app's flat config.
+struct Preset {
+  imports: Vec<&'static str>,
+  configs: Vec<&'static str>,
+  ignores: Vec<&'static str>,
+  globals: &'static str,
+  packages: Vec<&'static str>,
+  /// Whether the preset already sets up typescript-eslint.
+  typescript: bool,
+}
+
+impl Preset {
+  fn new(ignores: Vec<&'static str>, globals: &'static str) -> Self {
+    Self {
+      imports: Vec::new(),
+      configs: Vec::new(),
+      ignores,
+      globals,
+      packages: Vec::new(),
+      typescript: false,
+    }
+  }
+
+  fn with(mut self, import: &'static str, config: &'static str, package: &'static str) -> Self {
+    self.imports.push(import);
+    self.configs.push(config);
+    self.packages.push(package);
+    self
+  }
+
+  /// Adds a config that only applies to TypeScript apps.
+  fn with_ts(mut self, lang: &Lang, config: &'static str) -> Self {
+    if *lang == Lang::Ts {
+      self.configs.push(config);
+    }
+    self
+  }
+}
+
+/// Writes a flat ESLint config for the app, adds `lint` scripts and returns
+/// the dev packages it needs. Apps with nothing to lint are skipped, since it's
+/// enabled for every app.
+pub fn add_eslint(
+  path: &std::path::Path,
+  app_type: &AppType,
+  options: &TemplateOptions,
+) -> anyhow::Result<Vec<&'static str>> {
+  let preset = match determine_preset(app_type, options) {
+    Ok(preset) => preset,
+    Err(reason) => {
+      println!("skipped    ESLint, {reason}");
+      return Ok(vec![]);
+    }
+  };
+  let typescript = options.lang == Lang::Ts && !preset.typescript;
+  let package_json_path = path.join("package.json");
+  let package_json = std::fs::read_to_string(&package_json_path)?;
+
+  let mut imports = vec![
+    r#"import { defineConfig, globalIgnores } from "eslint/config";"#,
+    r#"import js from "@eslint/js";"#,
+    r#"import globals from "globals";"#,
+  ];
+  let mut configs = vec!["js.configs.recommended,"];
+  let mut packages = vec!["eslint", "@eslint/js", "globals"];
+
+  if typescript {
+    imports.push(r#"import tseslint from "typescript-eslint";"#);
+    configs.push("tseslint.configs.recommended,");
+    packages.push("typescript-eslint");
+  }
+  imports.extend(preset.imports);
+  configs.extend(preset.configs);
+  packages.extend(preset.packages);
+
+  let imports = imports.join("\n");
+  let configs = configs
+    .iter()
+    .map(|config| format!("  {config}"))
+    .collect::<Vec<_>>()
+    .join("\n");
+  let ignores = preset
+    .ignores
+    .iter()
+    .map(|ignore| format!("\"{ignore}\""))
+    .collect::<Vec<_>>()
+    .join(", ");
+  let globals = preset.globals;
+  let content = format!(
+    r#"{imports}
+
+export default defineConfig([
+  globalIgnores([{ignores}]),
+{configs}
+  {{
+    languageOptions: {{
+      globals: {globals},
+    }},
+  }},
+]);"#
+  );
+
+  // The config is an ES module, which `.js` files only are in `"type": "module"` packages.
+  let config_name = if package_json.contains(r#""type": "module""#) {
+    "eslint.config.js"
+  } else {
+    "eslint.config.mjs"
+  };
+
+  std::fs::write(path.join(config_name), content)?;
+  std::fs::write(package_json_path, add_lint_scripts(package_json))?;
+
+  Ok(packages)
+}
+
+fn add_lint_scripts(package_json: String) -> String {
+  let scripts = r#""lint": "eslint .",
+    "lint:fix": "eslint . --fix""#;
+
+  if package_json.contains(r#""lint": "next lint""#) {
+    package_json.replacen(r#""lint": "next lint""#, scripts, 1)
+  } else if package_json.contains(r#""scripts": {"#) {
+    package_json.replacen(
+      r#""scripts": {"#,
+      &format!("\"scripts\": {{\n    {scripts},"),
+      1,
+    )
+  } else {
+    let end = package_json.rfind('}').unwrap_or(package_json.len());
+
+    format!(
+      "{},\n  \"scripts\": {{\n    {scripts}\n  }}\n}}",
+      package_json[..end].trim_end()
+    )
+  }
+}
+
+/// The app's preset, or why there is nothing to lint.
+fn determine_preset(app_type: &AppType, options: &TemplateOptions) -> Result<Preset, &'static str> {
+  let lang = &options.lang;
+  let browser = "globals.browser";
+  let node = "globals.node";
+  let browser_and_node = "{ ...globals.browser, ...globals.node }";
+
+  let preset = match app_type {
+    AppType::React | AppType::Preact => react(Preset::new(vec!["dist"], browser)),
+    AppType::ReactRouter => react(Preset::new(vec!["build", ".react-router"], browser)),
+    AppType::Extension => react(Preset::new(
+      vec!["dist"],
+      r#"{ ...globals.browser, chrome: "readonly" }"#,
+    )),
+    AppType::Electron => react(Preset::new(
+      vec!["dist", "dist-electron", "release"],
+      browser_and_node,
+    )),
+    AppType::Package if options.package_kind == PackageKind::React => {
+      react(Preset::new(vec!["dist"], browser))
+    }
+    AppType::Package if options.package_kind == PackageKind::Tsconfig => {
+      return Err("tsconfig packages only contain JSON");
+    }
+    AppType::Next => {
+      let mut preset = Preset::new(
+        vec![".next", "out", "build", "next-env.d.ts"],
+        browser_and_node,
+      )
+      .with(
+        r#"import nextVitals from "eslint-config-next/core-web-vitals";"#,
+        "...nextVitals,",
+        "eslint-config-next",
+      );
+
+      if *lang == Lang::Ts {
+        preset
+          .imports
+          .push(r#"import nextTs from "eslint-config-next/typescript";"#);
+        preset.configs.push("...nextTs,");
+        preset.typescript = true;
+      }
+      preset
+    }
+    AppType::Astro | AppType::Starlight => Preset::new(vec!["dist", ".astro"], browser).with(
+      r#"import eslintPluginAstro from "eslint-plugin-astro";"#,
+      "...eslintPluginAstro.configs.recommended,",
+      "eslint-plugin-astro",
+    ),
+    AppType::Vue | AppType::Nuxt => Preset::new(vec!["dist", ".nuxt", ".output"], browser)
+      .with(
+        r#"import pluginVue from "eslint-plugin-vue";"#,
+        r#"...pluginVue.configs["flat/recommended"],"#,
+        "eslint-plugin-vue",
+      )
+      .with_ts(
+        lang,
+        r#"{
+    files: ["**/*.vue"],
+    languageOptions: { parserOptions: { parser: tseslint.parser } },
+  },"#,
+      ),
+    AppType::SvelteKit => Preset::new(vec![".svelte-kit", "build"], browser)
+      .with(
+        r#"import svelte from "eslint-plugin-svelte";"#,
+        "...svelte.configs.recommended,",
+        "eslint-plugin-svelte",
+      )
+      .with_ts(
+        lang,
+        r#"{
+    files: ["**/*.svelte", "**/*.svelte.ts"],
+    languageOptions: { parserOptions: { parser: tseslint.parser } },
+  },"#,
+      ),
+    AppType::Solid | AppType::SolidStart => {
+      let import = match lang {
+        Lang::Ts => r#"import solid from "eslint-plugin-solid/configs/typescript";"#,
+        Lang::Js => r#"import solid from "eslint-plugin-solid/configs/recommended";"#,
+      };
+
+      Preset::new(vec!["dist", ".output", ".vinxi"], browser).with(
+        import,
+        "solid,",
+        "eslint-plugin-solid",
+      )
+    }
+    AppType::Lit => Preset::new(vec!["dist"], browser).with(
+      r#"import { configs as litConfigs } from "eslint-plugin-lit";"#,
+      r#"litConfigs["flat/recommended"],"#,
+      "eslint-plugin-lit",
+    ),
+    AppType::Angular => Preset::new(vec!["dist", ".angular"], browser).with(
+      r#"import angular from "angular-eslint";"#,
+      r#"{
+    files: ["**/*.ts"],
+    extends: [angular.configs.tsRecommended],
+    processor: angular.processInlineTemplates,
+  },
+  {
+    files: ["**/*.html"],
+    extends: [angular.configs.templateRecommended, angular.configs.templateAccessibility],
+  },"#,
+      "angular-eslint",
+    ),
+    AppType::Expo => {
+      let mut preset = Preset::new(vec!["dist", ".expo"], browser).with(
+        r#"import expoConfig from "eslint-config-expo/flat.js";"#,
+        "expoConfig,",
+        "eslint-config-expo",
+      );
+
+      preset.typescript = true;
+      preset
+    }
+    AppType::Tauri => {
+      let (frontend_type, frontend_options) = options.tauri_frontend();
+      let mut preset = determine_preset(&frontend_type, &frontend_options)?;
+
+      preset.ignores.push("src-tauri");
+      preset
+    }
+    AppType::Wasm => Preset::new(vec!["dist", "wasm"], browser),
+    AppType::VitePress => Preset::new(vec!["docs/.vitepress/dist", "docs/.vitepress/cache"], node),
+    AppType::Node | AppType::Nest | AppType::Library | AppType::Cli | AppType::Package => {
+      Preset::new(vec!["dist"], node)
+    }
+    AppType::Workspace => {
+      return Err("add it to the apps inside the workspace, or share it as an eslint package");
+    }
+  };
+
+  Ok(preset)
+}
+
+fn react(preset: Preset) -> Preset {
+  preset
+    .with(
+      r#"import reactHooks from "eslint-plugin-react-hooks";"#,
+      "reactHooks.configs.flat.recommended,",
+      "eslint-plugin-react-hooks",
+    )
+    .with(
+      r#"import jsxA11y from "eslint-plugin-jsx-a11y";"#,
+      "jsxA11y.flatConfigs.recommended,",
+      "eslint-plugin-jsx-a11y",
+    )
+}
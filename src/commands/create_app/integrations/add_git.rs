@@ -2,11 +2,15 @@ use std::io::Write;
 
 use crate::cli::AppType;
 
-pub fn add_git(path: &std::path::PathBuf, app_type: &AppType) -> anyhow::Result<()> {
-  std::process::Command::new("git")
-    .arg("init")
-    .current_dir(path)
-    .spawn()?;
+/// Writes the app's gitignore, and starts a repository unless the app lives
+/// inside a workspace that has its own.
+pub fn add_git(path: &std::path::PathBuf, app_type: &AppType, init: bool) -> anyhow::Result<()> {
+  if init {
+    std::process::Command::new("git")
+      .arg("init")
+      .current_dir(path)
+      .spawn()?;
+  }
 
   let path = path.to_str().unwrap();
   let gitignore_path = std::path::PathBuf::from(format!("{path}/.gitignore"));
@@ -20,7 +24,7 @@ pub fn add_git(path: &std::path::PathBuf, app_type: &AppType) -> anyhow::Result<
 
 fn determine_content(app_type: &AppType) -> String {
   match app_type {
-    AppType::React => r"# Logs
+    AppType::React | AppType::Solid | AppType::Preact | AppType::Lit => r"# Logs
 logs
 *.log
 npm-debug.log*
@@ -80,7 +84,7 @@ yarn-error.log*
 *.tsbuildinfo
 next-env.d.ts"
       .to_owned(),
-    AppType::Astro => r"# build output
+    AppType::Astro | AppType::Starlight => r"# build output
 dist/
 
 # dependencies
@@ -98,6 +102,360 @@ pnpm-debug.log*
 .env.production
 
 # macOS-specific files
+.DS_Store"
+      .to_owned(),
+    AppType::Vue => r"# Logs
+logs
+*.log
+npm-debug.log*
+yarn-debug.log*
+yarn-error.log*
+pnpm-debug.log*
+lerna-debug.log*
+
+node_modules
+.DS_Store
+dist
+dist-ssr
+coverage
+*.local
+
+# Editor directories and files
+.vscode/*
+!.vscode/extensions.json
+.idea
+*.suo
+*.ntvs*
+*.njsproj
+*.sln
+*.sw?
+
+*.tsbuildinfo"
+      .to_owned(),
+    AppType::Nuxt => r"# Nuxt dev/build outputs
+.output
+.data
+.nuxt
+.nitro
+.cache
+dist
+
+# Node dependencies
+node_modules
+
+# Logs
+logs
+*.log
+
+# Misc
+.DS_Store
+.fleet
+.idea
+
+# Local env files
+.env
+.env.*
+!.env.example"
+      .to_owned(),
+    AppType::SvelteKit => r"node_modules
+
+# Output
+.output
+.vercel
+.netlify
+.wrangler
+/.svelte-kit
+/build
+
+# OS
+.DS_Store
+Thumbs.db
+
+# Env
+.env
+.env.*
+!.env.example
+!.env.test
+
+# Vite
+vite.config.js.timestamp-*
+vite.config.ts.timestamp-*"
+      .to_owned(),
+    AppType::SolidStart => r"dist
+.solid
+.output
+.vercel
+.netlify
+.vinxi
+app.config.timestamp_*.js
+
+# Environment
+.env
+.env*.local
+
+# dependencies
+/node_modules
+
+# IDEs and editors
+/.idea
+.project
+.classpath
+*.launch
+.settings/
+
+# System Files
+.DS_Store
+Thumbs.db"
+      .to_owned(),
+    AppType::ReactRouter => r"# dependencies
+/node_modules/
+
+# React Router
+/.react-router/
+/build/
+
+# Env
+.env
+.env.*
+!.env.example
+
+# System Files
+.DS_Store
+Thumbs.db"
+      .to_owned(),
+    AppType::Angular => r"# Compiled output
+/dist
+/tmp
+/out-tsc
+
+# Node
+/node_modules
+npm-debug.log
+yarn-error.log
+
+# IDEs and editors
+.idea/
+.vscode/*
+!.vscode/extensions.json
+
+# Angular
+/.angular/cache
+.sass-cache/
+/coverage
+/libpeerconnection.log
+testem.log
+/typings
+
+# System files
+.DS_Store
+Thumbs.db"
+      .to_owned(),
+    AppType::Node | AppType::Nest => r"# dependencies
+/node_modules
+
+# build output
+/dist
+
+# logs
+logs
+*.log
+npm-debug.log*
+pnpm-debug.log*
+
+# testing
+/coverage
+
+# env
+.env
+.env.*
+!.env.example
+
+# System Files
+.DS_Store
+Thumbs.db"
+      .to_owned(),
+    AppType::Library | AppType::Cli => r"# dependencies
+/node_modules
+
+# build output
+/dist
+*.tgz
+
+# testing
+/coverage
+
+# logs
+*.log
+
+# System Files
+.DS_Store
+Thumbs.db"
+      .to_owned(),
+    AppType::Extension => r"# dependencies
+node_modules
+
+# build output
+dist
+*.zip
+
+# logs
+*.log
+
+# Editor directories and files
+.vscode/*
+!.vscode/extensions.json
+.idea
+.DS_Store"
+      .to_owned(),
+    AppType::Electron => r"# dependencies
+node_modules
+
+# build output
+dist
+dist-electron
+release
+
+# logs
+*.log
+
+# Editor directories and files
+.vscode/*
+!.vscode/extensions.json
+.idea
+.DS_Store"
+      .to_owned(),
+    AppType::Tauri => r"# dependencies
+node_modules
+
+# frontend build output
+dist
+build
+.svelte-kit
+
+# Tauri
+/src-tauri/target/
+/src-tauri/gen/schemas
+
+# logs
+*.log
+
+# Editor directories and files
+.vscode/*
+!.vscode/extensions.json
+.idea
+.DS_Store"
+      .to_owned(),
+    AppType::Wasm => r"# dependencies
+node_modules
+
+# build output
+dist
+
+# Rust
+/wasm/target/
+/wasm/pkg/
+
+# logs
+*.log
+
+# Editor directories and files
+.vscode/*
+!.vscode/extensions.json
+.idea
+.DS_Store"
+      .to_owned(),
+    AppType::Expo => r"# dependencies
+node_modules/
+
+# Expo
+.expo/
+dist/
+web-build/
+expo-env.d.ts
+
+# native folders generated by expo prebuild
+/ios
+/android
+
+# native signing
+*.jks
+*.p8
+*.p12
+*.key
+*.mobileprovision
+
+# Metro
+.metro-health-check*
+
+# local env files
+.env*.local
+
+# logs
+*.log
+
+# Editor directories and files
+.vscode/*
+!.vscode/extensions.json
+.idea
+.DS_Store"
+      .to_owned(),
+    AppType::VitePress => r"# dependencies
+node_modules
+
+# VitePress
+docs/.vitepress/dist
+docs/.vitepress/cache
+
+# logs
+*.log
+
+# Editor directories and files
+.vscode/*
+!.vscode/extensions.json
+.idea
+.DS_Store"
+      .to_owned(),
+    AppType::Workspace => r"# dependencies
+node_modules
+
+# build output
+dist
+.next
+.astro
+.svelte-kit
+.nuxt
+.output
+
+# task runner caches
+.turbo
+.nx/cache
+.nx/workspace-data
+
+# logs
+*.log
+
+# local env files
+.env*.local
+
+# Editor directories and files
+.vscode/*
+!.vscode/extensions.json
+.idea
+.DS_Store"
+      .to_owned(),
+    AppType::Package => r"# dependencies
+node_modules
+
+# build output
+dist
+
+# logs
+*.log
+
+# Editor directories and files
+.vscode/*
+!.vscode/extensions.json
+.idea
 .DS_Store"
       .to_owned(),
   }
@@ -1,3 +1,255 @@
-pub fn add_tailwind() -> anyhow::Result<()> {
-  todo!();
+use crate::cli::{AppType, TemplateOptions};
+
+const TAILWIND_IMPORT: &str = r#"@import "tailwindcss";"#;
+
+/// Wires Tailwind into the generated app and returns the dev packages it needs.
+/// App types Tailwind can't style are skipped, since it's enabled for every app.
+pub fn add_tailwind(
+  path: &std::path::Path,
+  app_type: &AppType,
+  options: &TemplateOptions,
+) -> anyhow::Result<Vec<&'static str>> {
+  match app_type {
+    AppType::React | AppType::Vue | AppType::Solid | AppType::Preact => {
+      let entry = match app_type {
+        AppType::React | AppType::Preact => format!("src/main.{}", options.lang.jsx_ext()),
+        AppType::Solid => format!("src/index.{}", options.lang.jsx_ext()),
+        _ => format!("src/main.{}", options.lang.script_ext()),
+      };
+
+      add_vite_plugin(&path.join(format!("vite.config.{}", options.lang.script_ext())))?;
+      std::fs::write(path.join("src/index.css"), TAILWIND_IMPORT)?;
+      patch_file(&path.join(entry), |content| {
+        format!("import \"./index.css\";\n{content}")
+      })?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+    AppType::Next => {
+      let src = if options.src_dir { "src/" } else { "" };
+
+      std::fs::write(
+        path.join("postcss.config.mjs"),
+        r#"const config = {
+  plugins: {
+    "@tailwindcss/postcss": {},
+  },
+};
+
+export default config;"#,
+      )?;
+      std::fs::write(
+        path.join(format!("{src}styles/globals.css")),
+        TAILWIND_IMPORT,
+      )?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/postcss", "postcss"])
+    }
+    AppType::Astro => {
+      add_nested_vite_plugin(&path.join("astro.config.mjs"))?;
+      std::fs::create_dir_all(path.join("src/styles"))?;
+      std::fs::write(path.join("src/styles/global.css"), TAILWIND_IMPORT)?;
+      patch_file(&path.join("src/layouts/Layout.astro"), |content| {
+        content.replacen("---\n", "---\nimport \"../styles/global.css\";\n", 1)
+      })?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+    AppType::Starlight => {
+      add_nested_vite_plugin(&path.join("astro.config.mjs"))?;
+      patch_file(&path.join("astro.config.mjs"), |content| {
+        content.replacen(
+          "starlight({",
+          "starlight({\n      customCss: [\"./src/styles/global.css\"],",
+          1,
+        )
+      })?;
+      std::fs::create_dir_all(path.join("src/styles"))?;
+      std::fs::write(
+        path.join("src/styles/global.css"),
+        r#"@layer base, starlight, theme, components, utilities;
+
+@import "@astrojs/starlight-tailwind";
+@import "tailwindcss/theme.css" layer(theme);
+@import "tailwindcss/utilities.css" layer(utilities);"#,
+      )?;
+
+      Ok(vec![
+        "tailwindcss",
+        "@tailwindcss/vite",
+        "@astrojs/starlight-tailwind",
+      ])
+    }
+    AppType::VitePress => {
+      let ext = options.lang.script_ext();
+
+      add_nested_vite_plugin(&path.join(format!("docs/.vitepress/config.{ext}")))?;
+      std::fs::create_dir_all(path.join("docs/.vitepress/theme"))?;
+      std::fs::write(
+        path.join("docs/.vitepress/theme/style.css"),
+        TAILWIND_IMPORT,
+      )?;
+      std::fs::write(
+        path.join(format!("docs/.vitepress/theme/index.{ext}")),
+        r#"import DefaultTheme from "vitepress/theme";
+import "./style.css";
+
+export default DefaultTheme;"#,
+      )?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+    AppType::SvelteKit => {
+      add_vite_plugin(&path.join(format!("vite.config.{}", options.lang.script_ext())))?;
+      std::fs::write(path.join("src/app.css"), TAILWIND_IMPORT)?;
+      patch_file(&path.join("src/routes/+layout.svelte"), |content| {
+        content.replacen(">\n", ">\n  import \"../app.css\";\n\n", 1)
+      })?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+    AppType::Angular => {
+      std::fs::write(
+        path.join(".postcssrc.json"),
+        r#"{
+  "plugins": {
+    "@tailwindcss/postcss": {}
+  }
+}"#,
+      )?;
+      std::fs::write(path.join("src/styles.css"), TAILWIND_IMPORT)?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/postcss", "postcss"])
+    }
+    AppType::Library | AppType::Cli => {
+      skip_tailwind("libraries and CLI tools have no app to style with Tailwind")
+    }
+    AppType::Node | AppType::Nest => {
+      skip_tailwind("backend apps have no frontend to style with Tailwind")
+    }
+    AppType::Package => skip_tailwind("shared packages are styled by the apps that use them"),
+    AppType::Workspace => skip_tailwind("add Tailwind to the apps inside the workspace instead"),
+    AppType::Expo => {
+      skip_tailwind("its CSS doesn't reach React Native views, use NativeWind instead")
+    }
+    AppType::Lit => skip_tailwind("its styles don't reach the shadow DOM of Lit elements"),
+    AppType::Tauri => {
+      let (frontend_type, frontend_options) = options.tauri_frontend();
+
+      add_tailwind(path, &frontend_type, &frontend_options)
+    }
+    AppType::Electron => {
+      add_vite_plugin(&path.join("vite.config.mjs"))?;
+      std::fs::write(path.join("src/index.css"), TAILWIND_IMPORT)?;
+      patch_file(
+        &path.join(format!("src/main.{}", options.lang.jsx_ext())),
+        |content| format!("import \"./index.css\";\n{content}"),
+      )?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+    AppType::Extension => {
+      let jsx_ext = options.lang.jsx_ext();
+
+      add_vite_plugin(&path.join(format!("vite.config.{}", options.lang.script_ext())))?;
+      std::fs::write(path.join("src/index.css"), TAILWIND_IMPORT)?;
+      for page in ["popup", "options"] {
+        patch_file(
+          &path.join(format!("src/{page}/main.{jsx_ext}")),
+          |content| format!("import \"../index.css\";\n{content}"),
+        )?;
+      }
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+    AppType::Wasm => {
+      add_vite_plugin(&path.join("vite.config.ts"))?;
+      std::fs::write(path.join("src/index.css"), TAILWIND_IMPORT)?;
+      patch_file(&path.join("src/main.ts"), |content| {
+        format!("import \"./index.css\";\n{content}")
+      })?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+    AppType::ReactRouter => {
+      let jsx_ext = options.lang.jsx_ext();
+
+      add_vite_plugin(&path.join(format!("vite.config.{}", options.lang.script_ext())))?;
+      std::fs::write(path.join("app/app.css"), TAILWIND_IMPORT)?;
+      patch_file(&path.join(format!("app/root.{jsx_ext}")), |content| {
+        format!("import \"./app.css\";\n{content}")
+      })?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+    AppType::SolidStart => {
+      let jsx_ext = options.lang.jsx_ext();
+
+      add_nested_vite_plugin(&path.join(format!("app.config.{}", options.lang.script_ext())))?;
+      std::fs::write(path.join("src/app.css"), TAILWIND_IMPORT)?;
+      patch_file(&path.join(format!("src/app.{jsx_ext}")), |content| {
+        format!("import \"./app.css\";\n{content}")
+      })?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+    AppType::Nuxt => {
+      let nuxtconfig_path = path.join(format!("nuxt.config.{}", options.lang.script_ext()));
+
+      patch_file(&nuxtconfig_path, |content| {
+        format!("import tailwindcss from \"@tailwindcss/vite\";\n\n{content}").replacen(
+          "defineNuxtConfig({",
+          "defineNuxtConfig({\n  css: [\"~/assets/css/main.css\"],\n  vite: {\n    plugins: [tailwindcss()],\n  },",
+          1,
+        )
+      })?;
+      std::fs::create_dir_all(path.join("assets/css"))?;
+      std::fs::write(path.join("assets/css/main.css"), TAILWIND_IMPORT)?;
+
+      Ok(vec!["tailwindcss", "@tailwindcss/vite"])
+    }
+  }
+}
+
+fn add_vite_plugin(viteconfig_path: &std::path::Path) -> anyhow::Result<()> {
+  patch_file(viteconfig_path, |content| {
+    format!("import tailwindcss from \"@tailwindcss/vite\";\n{content}").replacen(
+      "plugins: [",
+      "plugins: [tailwindcss(), ",
+      1,
+    )
+  })
+}
+
+/// Adds the plugin to frameworks that take Vite options under a `vite` key of
+/// their own `defineConfig`.
+fn add_nested_vite_plugin(config_path: &std::path::Path) -> anyhow::Result<()> {
+  patch_file(config_path, |content| {
+    let vite = "vite: {\n    plugins: [tailwindcss()],\n  },";
+    let content = format!("import tailwindcss from \"@tailwindcss/vite\";\n{content}");
+
+    if content.contains("defineConfig({})") {
+      content.replacen(
+        "defineConfig({})",
+        &format!("defineConfig({{\n  {vite}\n}})"),
+        1,
+      )
+    } else {
+      content.replacen("defineConfig({", &format!("defineConfig({{\n  {vite}"), 1)
+    }
+  })
+}
+
+fn patch_file(path: &std::path::Path, patch: impl FnOnce(String) -> String) -> anyhow::Result<()> {
+  let content = std::fs::read_to_string(path)?;
+
+  std::fs::write(path, patch(content))?;
+
+  Ok(())
+}
+
+fn skip_tailwind(reason: &str) -> anyhow::Result<Vec<&'static str>> {
+  println!("skipped    Tailwind, {reason}");
+
+  Ok(vec![])
 }
@@ -0,0 +1,335 @@
+use crate::cli::{AppType, PackageKind, PackageManager, Pipeline, TemplateOptions};
+use crate::configs::{AppManifest, CreateAppConfig};
+
+pub struct Workspace {
+  pub name: String,
+  pub path: std::path::PathBuf,
+  pub package_manager: PackageManager,
+  pub pipeline: Pipeline,
+}
+
+/// Finds the deez workspace `dir` belongs to.
+pub fn find_workspace(dir: &std::path::Path) -> anyhow::Result<Workspace> {
+  for dir in dir.ancestors() {
+    if let Ok(manifest) = AppManifest::load(dir) {
+      if matches!(manifest.app_type, AppType::Workspace) {
+        return Ok(Workspace {
+          name: manifest.app_name,
+          path: dir.to_path_buf(),
+          package_manager: manifest.options.package_manager,
+          pipeline: manifest.options.pipeline,
+        });
+      }
+    }
+  }
+
+  anyhow::bail!("No deez workspace found, create one with `deez create workspace <name>`")
+}
+
+/// Reads the package manager from the stored create config, which is the one
+/// deez installs packages with and app scripts call.
+pub fn configured_package_manager() -> anyhow::Result<PackageManager> {
+  use clap::ValueEnum;
+
+  let cfg: CreateAppConfig = confy::load(super::DEEZ_CREATE_APP_CONFIG, None)?;
+
+  PackageManager::from_str(&cfg.package_manager, true).map_err(|_| {
+    anyhow::anyhow!(
+      "deez supports pnpm, npm and yarn, not {}",
+      cfg.package_manager
+    )
+  })
+}
+
+/// Makes the app's tsconfig extend the workspace's shared base config.
+pub fn link_tsconfig(app_path: &std::path::Path) -> anyhow::Result<()> {
+  let tsconfig_path = app_path.join("tsconfig.json");
+  let base = "../../tsconfig.base.json";
+
+  if !tsconfig_path.exists() {
+    return Ok(());
+  }
+
+  let content = std::fs::read_to_string(&tsconfig_path)?;
+  // Later entries win, so a framework config the app already extends keeps precedence.
+  let extended = content
+    .split_once("\"extends\": \"")
+    .and_then(|(before, after)| {
+      after
+        .split_once('"')
+        .map(|(value, rest)| format!("{before}\"extends\": [\"{base}\", \"{value}\"]{rest}"))
+    });
+  let content = extended
+    .unwrap_or_else(|| content.replacen('{', &format!("{{\n  \"extends\": \"{base}\","), 1));
+
+  std::fs::write(tsconfig_path, content)?;
+
+  Ok(())
+}
+
+/// Registers the app's build outputs with the workspace's task runner, so
+/// their cache covers whatever the app's template builds into.
+pub fn add_to_pipeline(
+  workspace: &Workspace,
+  app_path: &std::path::Path,
+  app_name: &str,
+  app_type: &AppType,
+  options: &TemplateOptions,
+) -> anyhow::Result<()> {
+  let outputs = build_outputs(app_type, options);
+
+  match workspace.pipeline {
+    Pipeline::None => {}
+    Pipeline::Turbo => {
+      let turbo_json_path = workspace.path.join("turbo.json");
+      let outputs = outputs
+        .iter()
+        .map(|output| format!("\"{output}/**\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let task = format!(
+        r#""tasks": {{
+    "{app_name}#build": {{
+      "dependsOn": ["^build"],
+      "outputs": [{outputs}]
+    }},"#
+      );
+      let content = std::fs::read_to_string(&turbo_json_path)?;
+
+      if content.contains("\"tasks\": {") {
+        std::fs::write(turbo_json_path, content.replacen("\"tasks\": {", &task, 1))?;
+      } else {
+        println!("register   {app_name}#build by hand, turbo.json has no \"tasks\" block");
+      }
+    }
+    Pipeline::Nx => {
+      let outputs = outputs
+        .iter()
+        .map(|output| format!("\"{{projectRoot}}/{output}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+      let content = format!(
+        r#"{{
+  "name": "{app_name}",
+  "$schema": "../../node_modules/nx/schemas/project-schema.json",
+  "targets": {{
+    "build": {{
+      "outputs": [{outputs}]
+    }}
+  }}
+}}"#
+      );
+
+      std::fs::write(app_path.join("project.json"), content)?;
+    }
+  };
+
+  Ok(())
+}
+
+/// The folders an app's `build` script writes to.
+fn build_outputs(app_type: &AppType, options: &TemplateOptions) -> Vec<&'static str> {
+  match app_type {
+    // Packages ship their sources, there's nothing to build.
+    AppType::Package => vec![],
+    AppType::Next => vec![".next"],
+    AppType::Nuxt | AppType::SolidStart => vec![".output"],
+    AppType::SvelteKit => vec![".svelte-kit", "build"],
+    AppType::ReactRouter => vec!["build"],
+    AppType::VitePress => vec!["docs/.vitepress/dist"],
+    AppType::Electron => vec!["dist", "dist-electron"],
+    AppType::Wasm => vec!["dist", "wasm/pkg"],
+    AppType::Tauri => build_outputs(&options.tauri_frontend().0, options),
+    _ => vec!["dist"],
+  }
+}
+
+/// Checks that a package of this kind can be linked into the given apps,
+/// before anything is created.
+pub fn check_link_targets(
+  workspace: &Workspace,
+  kind: &PackageKind,
+  apps: &[String],
+) -> anyhow::Result<()> {
+  for app in apps {
+    let app_path = workspace.path.join("apps").join(app);
+
+    if !app_path.join("package.json").exists() {
+      anyhow::bail!("No app named {app} in the workspace");
+    }
+    if *kind == PackageKind::React && !renders_react_dom(&app_path) {
+      anyhow::bail!("React packages can only be linked into React apps, apps/{app} isn't one");
+    }
+  }
+
+  Ok(())
+}
+
+/// Adds the package to the given workspace apps as a workspace dependency.
+/// React packages ship their sources, so they only link into React DOM apps,
+/// which also get path aliases to them and transpile them when built by Next.
+pub fn link_package(
+  workspace: &Workspace,
+  package_path: &std::path::Path,
+  package_name: &str,
+  kind: &PackageKind,
+  apps: &[String],
+) -> anyhow::Result<()> {
+  let package_dir = package_path.file_name().unwrap().to_string_lossy();
+  let version = match workspace.package_manager {
+    PackageManager::Npm => "*",
+    PackageManager::Pnpm | PackageManager::Yarn => "workspace:*",
+  };
+  let dependencies = match kind {
+    PackageKind::React => "dependencies",
+    PackageKind::Tsconfig | PackageKind::Eslint => "devDependencies",
+  };
+
+  for app in apps {
+    let app_path = workspace.path.join("apps").join(app);
+
+    patch_file(&app_path.join("package.json"), |content| {
+      add_dependencies(
+        content,
+        dependencies,
+        &[format!("\"{package_name}\": \"{version}\"")],
+      )
+    })?;
+
+    if *kind == PackageKind::React {
+      patch_file(&app_path.join("tsconfig.json"), |content| {
+        let src = format!("../../packages/{package_dir}/src");
+        let alias =
+          format!("\"{package_name}\": [\"{src}\"],\n      \"{package_name}/*\": [\"{src}/*\"]");
+
+        if content.contains("\"paths\": {") {
+          content.replacen("\"paths\": {", &format!("\"paths\": {{\n      {alias},"), 1)
+        } else if content.contains("\"compilerOptions\": {") {
+          content.replacen(
+            "\"compilerOptions\": {",
+            &format!("\"compilerOptions\": {{\n    \"paths\": {{\n      {alias}\n    }},"),
+            1,
+          )
+        } else {
+          let end = content.rfind('}').unwrap_or(content.len());
+
+          format!(
+            "{},\n  \"compilerOptions\": {{\n    \"paths\": {{\n      {alias}\n    }}\n  }}\n}}",
+            content[..end].trim_end()
+          )
+        }
+      })?;
+
+      for next_config in ["next.config.js", "next.config.mjs", "next.config.ts"] {
+        patch_file(&app_path.join(next_config), |content| {
+          if content.contains("transpilePackages: [") {
+            content.replacen(
+              "transpilePackages: [",
+              &format!("transpilePackages: [\"{package_name}\", "),
+              1,
+            )
+          } else {
+            content.replacen(
+              "const nextConfig = {",
+              &format!("const nextConfig = {{\n  transpilePackages: [\"{package_name}\"],"),
+              1,
+            )
+          }
+        })?;
+      }
+    }
+
+    println!("linked     {package_name} into apps/{app}");
+  }
+
+  Ok(())
+}
+
+/// Lists the packages in the app's package.json, so the install at the
+/// workspace root fetches them along with everything else.
+pub fn write_dependencies(
+  app_path: &std::path::Path,
+  packages: &[&str],
+  dev_packages: &[&str],
+) -> anyhow::Result<()> {
+  for (dependencies, packages) in [
+    ("dependencies", packages),
+    ("devDependencies", dev_packages),
+  ] {
+    if packages.is_empty() {
+      continue;
+    }
+
+    let entries = packages
+      .iter()
+      .map(|package| format!("\"{package}\": \"latest\""))
+      .collect::<Vec<_>>();
+
+    patch_file(&app_path.join("package.json"), |content| {
+      add_dependencies(content, dependencies, &entries)
+    })?;
+  }
+
+  Ok(())
+}
+
+/// Adds entries to a package.json dependency block, creating the block when
+/// the file has none.
+fn add_dependencies(content: String, dependencies: &str, entries: &[String]) -> String {
+  let block = format!("\"{dependencies}\": {{");
+  let entries = entries.join(",\n    ");
+
+  match content.find(&block) {
+    Some(start) => {
+      let items_start = start + block.len();
+      let separator = if content[items_start..].trim_start().starts_with('}') {
+        ""
+      } else {
+        ","
+      };
+
+      format!(
+        "{}\n    {entries}{separator}{}",
+        &content[..items_start],
+        &content[items_start..]
+      )
+    }
+    None => {
+      let end = content.rfind('}').unwrap_or(content.len());
+
+      format!(
+        "{},\n  {block}\n    {entries}\n  }}\n}}",
+        content[..end].trim_end()
+      )
+    }
+  }
+}
+
+/// Whether the app renders with React DOM, which is what React packages' components target.
+fn renders_react_dom(app_path: &std::path::Path) -> bool {
+  match AppManifest::load(app_path) {
+    Ok(manifest) => match manifest.app_type {
+      AppType::React
+      | AppType::Next
+      | AppType::ReactRouter
+      | AppType::Electron
+      | AppType::Extension => true,
+      AppType::Tauri => matches!(manifest.options.tauri_frontend().0, AppType::React),
+      _ => false,
+    },
+    Err(_) => false,
+  }
+}
+
+/// Rewrites a file if it exists, leaving apps without it untouched.
+fn patch_file(path: &std::path::Path, patch: impl FnOnce(String) -> String) -> anyhow::Result<()> {
+  if !path.exists() {
+    return Ok(());
+  }
+
+  let content = std::fs::read_to_string(path)?;
+
+  std::fs::write(path, patch(content))?;
+
+  Ok(())
+}
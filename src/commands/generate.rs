@@ -0,0 +1,157 @@
+mod generators;
+
+use self::generators::{GenerateTarget, GeneratedFile};
+use crate::cli::{AppType, Generate, GenerateKind, Lang, NextRouter, PackageKind, TemplateOptions};
+use crate::configs::AppManifest;
+
+const UNSUPPORTED: &str =
+  "Unsupported app type, generators are available for React, Next, Astro and NestJS apps";
+
+pub fn generate(generator: Generate) -> anyhow::Result<()> {
+  use crate::utils::to_pascal_case;
+  use anyhow::bail;
+
+  let Generate {
+    kind,
+    name,
+    app_path,
+    test,
+    story,
+    css_module,
+  } = generator;
+
+  let name = match (name, &kind) {
+    (Some(name), _) => name,
+    (None, GenerateKind::Layout) => "Layout".to_owned(),
+    (None, _) => bail!("A name is required for this generator"),
+  };
+  if !to_pascal_case(&name).starts_with(char::is_alphabetic) {
+    bail!("Invalid name {name:?}, names must start with a letter");
+  }
+
+  let (app_type, options) = detect_app(&app_path)?;
+  let target = GenerateTarget {
+    kind,
+    name,
+    test,
+    story,
+    css_module,
+    options,
+  };
+  let files = generate_files(&app_type, &target)?;
+
+  write_files(&app_path, files)?;
+  if matches!(app_type, AppType::Nest) {
+    self::generators::register_nest(&app_path, &target)?;
+  }
+
+  Ok(())
+}
+
+fn detect_app(app_path: &std::path::Path) -> anyhow::Result<(AppType, TemplateOptions)> {
+  if let Ok(manifest) = AppManifest::load(app_path) {
+    return Ok((manifest.app_type, manifest.options));
+  }
+
+  let lang = if app_path.join("jsconfig.json").exists() {
+    Lang::Js
+  } else {
+    Lang::Ts
+  };
+
+  if app_path.join("next.config.js").exists() || app_path.join("next.config.mjs").exists() {
+    let src_dir = app_path.join("src").exists();
+    let src_path = if src_dir {
+      app_path.join("src")
+    } else {
+      app_path.to_path_buf()
+    };
+    let router = if src_path.join("app").exists() {
+      NextRouter::App
+    } else {
+      NextRouter::Pages
+    };
+
+    Ok((
+      AppType::Next,
+      TemplateOptions {
+        router,
+        src_dir,
+        lang,
+        ..Default::default()
+      },
+    ))
+  } else if app_path.join("astro.config.mjs").exists() {
+    Ok((
+      AppType::Astro,
+      TemplateOptions {
+        lang,
+        ..Default::default()
+      },
+    ))
+  } else if uses_react_plugin(app_path) {
+    Ok((
+      AppType::React,
+      TemplateOptions {
+        lang,
+        ..Default::default()
+      },
+    ))
+  } else if app_path.join("nest-cli.json").exists() {
+    Ok((
+      AppType::Nest,
+      TemplateOptions {
+        lang,
+        ..Default::default()
+      },
+    ))
+  } else {
+    anyhow::bail!(UNSUPPORTED)
+  }
+}
+
+/// Vite also builds Vue, Svelte, Solid and others, so only the React plugin marks a React app.
+fn uses_react_plugin(app_path: &std::path::Path) -> bool {
+  ["vite.config.ts", "vite.config.js"].iter().any(|file| {
+    std::fs::read_to_string(app_path.join(file))
+      .map(|content| content.contains("@vitejs/plugin-react"))
+      .unwrap_or(false)
+  })
+}
+
+fn generate_files(
+  app_type: &AppType,
+  target: &GenerateTarget,
+) -> anyhow::Result<Vec<GeneratedFile>> {
+  use self::generators::{generate_astro, generate_nest, generate_next, generate_react};
+
+  match app_type {
+    AppType::React => generate_react(target),
+    AppType::Next => generate_next(target),
+    AppType::Astro => generate_astro(target),
+    AppType::Nest => generate_nest(target),
+    AppType::Package if target.options.package_kind == PackageKind::React => generate_react(target),
+    _ => anyhow::bail!(UNSUPPORTED),
+  }
+}
+
+fn write_files(app_path: &std::path::Path, files: Vec<GeneratedFile>) -> anyhow::Result<()> {
+  for file in &files {
+    if app_path.join(&file.path).exists() {
+      anyhow::bail!("{} already exists", file.path.display());
+    }
+  }
+
+  for GeneratedFile { path, content } in files {
+    let full_path = app_path.join(&path);
+
+    if let Some(parent) = full_path.parent() {
+      std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(full_path, content)?;
+
+    println!("created    {}", path.display());
+  }
+
+  Ok(())
+}
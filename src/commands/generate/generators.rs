@@ -0,0 +1,47 @@
+pub mod generate_astro;
+pub mod generate_nest;
+pub mod generate_next;
+pub mod generate_react;
+pub use generate_astro::generate_astro;
+pub use generate_nest::{generate_nest, register_nest};
+pub use generate_next::generate_next;
+pub use generate_react::generate_react;
+
+use crate::cli::{GenerateKind, TemplateOptions};
+
+pub const NEST_ONLY: &str = "Modules and controllers can only be generated in NestJS apps";
+
+pub struct GenerateTarget {
+  pub kind: GenerateKind,
+  pub name: String,
+  pub test: bool,
+  pub story: bool,
+  pub css_module: bool,
+  pub options: TemplateOptions,
+}
+
+pub struct GeneratedFile {
+  pub path: std::path::PathBuf,
+  pub content: String,
+}
+
+impl GeneratedFile {
+  pub fn new(path: String, content: String) -> Self {
+    Self {
+      path: std::path::PathBuf::from(path),
+      content,
+    }
+  }
+}
+
+/// Turns `foo`, `use-foo` or `useFoo` into `useFoo`.
+pub fn to_hook_name(name: &str) -> String {
+  use crate::utils::to_pascal_case;
+
+  let pascal = to_pascal_case(name);
+
+  match pascal.strip_prefix("Use") {
+    Some(rest) if rest.starts_with(char::is_uppercase) => format!("use{rest}"),
+    _ => format!("use{pascal}"),
+  }
+}
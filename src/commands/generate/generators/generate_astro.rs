@@ -0,0 +1,91 @@
+use super::{GenerateTarget, GeneratedFile, NEST_ONLY};
+use crate::cli::{GenerateKind, Lang};
+use crate::utils::{to_kebab_case, to_pascal_case};
+
+pub fn generate_astro(target: &GenerateTarget) -> anyhow::Result<Vec<GeneratedFile>> {
+  if target.test || target.story || target.css_module {
+    println!(
+      "Astro components keep their styles inline, skipping test, story and CSS module files"
+    );
+  }
+
+  match target.kind {
+    GenerateKind::Component => Ok(component_files(target)),
+    GenerateKind::Page => Ok(page_files(target)),
+    GenerateKind::Layout => Ok(layout_files(target)),
+    GenerateKind::ApiRoute => Ok(api_route_files(target)),
+    GenerateKind::Hook => anyhow::bail!("Astro apps have no hooks"),
+    GenerateKind::Module | GenerateKind::Controller => anyhow::bail!(NEST_ONLY),
+  }
+}
+
+fn component_files(target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let name = to_pascal_case(&target.name);
+
+  vec![GeneratedFile::new(
+    format!("src/components/{name}.astro"),
+    r#"---
+---
+
+<div></div>
+
+<style>
+</style>"#
+      .to_owned(),
+  )]
+}
+
+fn page_files(target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let route = to_kebab_case(&target.name);
+
+  vec![GeneratedFile::new(
+    format!("src/pages/{route}.astro"),
+    r#"---
+import Layout from "../layouts/Layout.astro";
+---
+
+<Layout></Layout>"#
+      .to_owned(),
+  )]
+}
+
+fn layout_files(target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let name = to_pascal_case(&target.name);
+
+  vec![GeneratedFile::new(
+    format!("src/layouts/{name}.astro"),
+    r#"---
+---
+
+<html lang="en">
+  <head>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width" />
+  </head>
+  <body>
+    <slot />
+  </body>
+</html>"#
+      .to_owned(),
+  )]
+}
+
+fn api_route_files(target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let ext = target.options.lang.script_ext();
+  let route = to_kebab_case(&target.name);
+  let (route_import, route_type) = match target.options.lang {
+    Lang::Ts => ("import type { APIRoute } from \"astro\";\n\n", ": APIRoute"),
+    Lang::Js => ("", ""),
+  };
+
+  vec![GeneratedFile::new(
+    format!("src/pages/api/{route}.{ext}"),
+    format!(
+      r#"{route_import}export const GET{route_type} = () => {{
+  return new Response(JSON.stringify({{}}), {{
+    headers: {{ "Content-Type": "application/json" }},
+  }});
+}};"#
+    ),
+  )]
+}
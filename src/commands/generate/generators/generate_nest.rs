@@ -0,0 +1,302 @@
+use super::{GenerateTarget, GeneratedFile};
+use crate::cli::GenerateKind;
+use crate::utils::{to_kebab_case, to_pascal_case};
+
+/// Generates Nest building blocks under `src/<name>/`, named the way
+/// `nest generate` names them. See `register_nest` for wiring them up.
+pub fn generate_nest(target: &GenerateTarget) -> anyhow::Result<Vec<GeneratedFile>> {
+  match target.kind {
+    GenerateKind::Module => Ok(module_files(target)),
+    GenerateKind::Controller | GenerateKind::ApiRoute => Ok(controller_files(target)),
+    _ => anyhow::bail!("NestJS apps only support the module and controller generators"),
+  }
+}
+
+/// Registers a generated module in `AppModule.imports`, and a generated
+/// controller in the `controllers` of its module, like `nest generate` does.
+/// A controller goes into the module of the same name when there is one.
+pub fn register_nest(app_path: &std::path::Path, target: &GenerateTarget) -> anyhow::Result<()> {
+  let file_name = to_kebab_case(&target.name);
+  let name = to_pascal_case(&target.name);
+  let app_module = "src/app.module.ts".to_owned();
+  let own_module = format!("src/{file_name}/{file_name}.module.ts");
+
+  let (module, class, from, key) = match target.kind {
+    GenerateKind::Module => (
+      app_module,
+      format!("{name}Module"),
+      format!("./{file_name}/{file_name}.module"),
+      "imports",
+    ),
+    _ if app_path.join(&own_module).exists() => (
+      own_module,
+      format!("{name}Controller"),
+      format!("./{file_name}.controller"),
+      "controllers",
+    ),
+    _ => (
+      app_module,
+      format!("{name}Controller"),
+      format!("./{file_name}/{file_name}.controller"),
+      "controllers",
+    ),
+  };
+  let import = format!("import {{ {class} }} from \"{from}\";");
+  let module_path = app_path.join(&module);
+  let content = std::fs::read_to_string(&module_path).unwrap_or_default();
+
+  match add_to_module(&content, &import, key, &class) {
+    Some(content) => {
+      std::fs::write(module_path, content)?;
+      println!("updated    {module}");
+    }
+    None => println!("register   {class} by hand, add `{import}` and list it in `{key}`"),
+  }
+
+  Ok(())
+}
+
+/// Imports the class and appends it to the `@Module` array under `key`,
+/// or returns `None` when there is no `@Module` or its array never closes.
+fn add_to_module(content: &str, import: &str, key: &str, class: &str) -> Option<String> {
+  let array = format!("{key}: [");
+
+  let content = if let Some(start) = content.find(&array) {
+    let items_start = start + array.len();
+    let items_end = items_start + closing_bracket(&content[items_start..])?;
+    let before = content[..items_end].trim_end();
+    let after = &content[items_end..];
+
+    if items_start == items_end {
+      format!("{before}{class}{after}")
+    } else if before.ends_with(',') {
+      format!("{before}\n    {class},\n  {after}")
+    } else {
+      format!("{before}, {class}{after}")
+    }
+  } else if content.contains("@Module({})") {
+    content.replacen(
+      "@Module({})",
+      &format!("@Module({{\n  {array}{class}],\n}})"),
+      1,
+    )
+  } else if content.contains("@Module({") {
+    content.replacen("@Module({", &format!("@Module({{\n  {array}{class}],"), 1)
+  } else {
+    return None;
+  };
+
+  // The import goes after the last one, keeping the file's import block together.
+  let imports_end = content
+    .match_indices("import ")
+    .filter(|(index, _)| *index == 0 || content[..*index].ends_with('\n'))
+    .last()
+    .and_then(|(index, _)| content[index..].find(';').map(|end| index + end + 1));
+
+  Some(match imports_end {
+    Some(end) => format!("{}\n{import}{}", &content[..end], &content[end..]),
+    None => format!("{import}\n{content}"),
+  })
+}
+
+/// Finds the `]` closing an array whose items start `items`, skipping nested
+/// brackets and string literals.
+fn closing_bracket(items: &str) -> Option<usize> {
+  let mut depth = 0;
+  let mut quote = None;
+  let mut escaped = false;
+
+  for (index, char) in items.char_indices() {
+    match (quote, char) {
+      (Some(_), _) if escaped => escaped = false,
+      (Some(_), '\\') => escaped = true,
+      (Some(open), _) if char == open => quote = None,
+      (Some(_), _) => {}
+      (None, '"' | '\'' | '`') => quote = Some(char),
+      (None, '[' | '(' | '{') => depth += 1,
+      (None, ']') if depth == 0 => return Some(index),
+      (None, ']' | ')' | '}') => depth -= 1,
+      (None, _) => {}
+    }
+  }
+
+  None
+}
+
+fn module_files(target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let file_name = to_kebab_case(&target.name);
+  let name = to_pascal_case(&target.name);
+
+  vec![GeneratedFile::new(
+    format!("src/{file_name}/{file_name}.module.ts"),
+    format!(
+      r#"import {{ Module }} from "@nestjs/common";
+
+@Module({{}})
+export class {name}Module {{}}"#
+    ),
+  )]
+}
+
+fn controller_files(target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let file_name = to_kebab_case(&target.name);
+  let name = to_pascal_case(&target.name);
+
+  let mut files = vec![GeneratedFile::new(
+    format!("src/{file_name}/{file_name}.controller.ts"),
+    format!(
+      r#"import {{ Controller, Get }} from "@nestjs/common";
+
+@Controller("{file_name}")
+export class {name}Controller {{
+  @Get()
+  findAll() {{
+    return [];
+  }}
+}}"#
+    ),
+  )];
+
+  if target.test {
+    files.push(GeneratedFile::new(
+      format!("src/{file_name}/{file_name}.controller.spec.ts"),
+      format!(
+        r#"import {{ Test }} from "@nestjs/testing";
+import {{ {name}Controller }} from "./{file_name}.controller";
+
+describe("{name}Controller", () => {{
+  let controller: {name}Controller;
+
+  beforeEach(async () => {{
+    const module = await Test.createTestingModule({{
+      controllers: [{name}Controller],
+    }}).compile();
+
+    controller = module.get({name}Controller);
+  }});
+
+  it("should be defined", () => {{
+    expect(controller).toBeDefined();
+  }});
+}});"#
+      ),
+    ));
+  }
+
+  files
+}
+
+#[cfg(test)]
+mod tests {
+  use super::add_to_module;
+
+  const IMPORT: &str = r#"import { UsersModule } from "./users/users.module";"#;
+
+  #[test]
+  fn appends_to_an_existing_array() {
+    let content = r#"import { Module } from "@nestjs/common";
+import { HealthModule } from "./health/health.module";
+
+@Module({
+  imports: [HealthModule],
+})
+export class AppModule {}"#;
+
+    assert_eq!(
+      add_to_module(content, IMPORT, "imports", "UsersModule").unwrap(),
+      r#"import { Module } from "@nestjs/common";
+import { HealthModule } from "./health/health.module";
+import { UsersModule } from "./users/users.module";
+
+@Module({
+  imports: [HealthModule, UsersModule],
+})
+export class AppModule {}"#
+    );
+  }
+
+  #[test]
+  fn appends_to_a_multi_line_array() {
+    let content = r#"import {
+  Module,
+} from "@nestjs/common";
+
+@Module({
+  imports: [
+    HealthModule,
+  ],
+})
+export class AppModule {}"#;
+
+    assert_eq!(
+      add_to_module(content, IMPORT, "imports", "UsersModule").unwrap(),
+      r#"import {
+  Module,
+} from "@nestjs/common";
+import { UsersModule } from "./users/users.module";
+
+@Module({
+  imports: [
+    HealthModule,
+    UsersModule,
+  ],
+})
+export class AppModule {}"#
+    );
+  }
+
+  #[test]
+  fn adds_the_array_to_an_empty_module() {
+    let content = r#"import { Module } from "@nestjs/common";
+
+@Module({})
+export class AppModule {}"#;
+
+    assert_eq!(
+      add_to_module(content, IMPORT, "imports", "UsersModule").unwrap(),
+      r#"import { Module } from "@nestjs/common";
+import { UsersModule } from "./users/users.module";
+
+@Module({
+  imports: [UsersModule],
+})
+export class AppModule {}"#
+    );
+  }
+
+  #[test]
+  fn skips_nested_arrays_and_strings() {
+    let content = r#"import { Module } from "@nestjs/common";
+
+@Module({
+  imports: [ConfigModule.forRoot({ load: [config], envFilePath: "[.env]" }), HealthModule],
+})
+export class AppModule {}"#;
+
+    assert_eq!(
+      add_to_module(content, IMPORT, "imports", "UsersModule").unwrap(),
+      r#"import { Module } from "@nestjs/common";
+import { UsersModule } from "./users/users.module";
+
+@Module({
+  imports: [ConfigModule.forRoot({ load: [config], envFilePath: "[.env]" }), HealthModule, UsersModule],
+})
+export class AppModule {}"#
+    );
+  }
+
+  #[test]
+  fn leaves_unclosed_arrays_alone() {
+    let content = "@Module({\n  imports: [ConfigModule.forRoot({ load: [config] }),\n";
+
+    assert_eq!(
+      add_to_module(content, IMPORT, "imports", "UsersModule"),
+      None
+    );
+  }
+
+  #[test]
+  fn leaves_files_without_a_module_alone() {
+    assert_eq!(add_to_module("", IMPORT, "imports", "UsersModule"), None);
+  }
+}
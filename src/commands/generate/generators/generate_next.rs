@@ -0,0 +1,110 @@
+use super::generate_react::{component_files, hook_files, layout_files};
+use super::{GenerateTarget, GeneratedFile, NEST_ONLY};
+use crate::cli::{GenerateKind, Lang, NextRouter};
+use crate::utils::{to_kebab_case, to_pascal_case};
+
+pub fn generate_next(target: &GenerateTarget) -> anyhow::Result<Vec<GeneratedFile>> {
+  let src = if target.options.src_dir { "src/" } else { "" };
+
+  match (&target.kind, &target.options.router) {
+    (GenerateKind::Component, _) => Ok(component_files(&format!("{src}components"), target, false)),
+    (GenerateKind::Layout, _) => Ok(layout_files(&format!("{src}components"), target)),
+    (GenerateKind::Hook, _) => Ok(hook_files(&format!("{src}hooks"), target, false)),
+    (GenerateKind::Page, NextRouter::Pages) => Ok(page_files(src, target)),
+    (GenerateKind::Page, NextRouter::App) => Ok(app_page_files(src, target)),
+    (GenerateKind::ApiRoute, NextRouter::Pages) => Ok(api_route_files(src, target)),
+    (GenerateKind::ApiRoute, NextRouter::App) => Ok(route_handler_files(src, target)),
+    (GenerateKind::Module | GenerateKind::Controller, _) => anyhow::bail!(NEST_ONLY),
+  }
+}
+
+fn page_files(src: &str, target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let ext = target.options.lang.jsx_ext();
+  let route = to_kebab_case(&target.name);
+  let name = to_pascal_case(&target.name);
+
+  vec![GeneratedFile::new(
+    format!("{src}pages/{route}.{ext}"),
+    format!(
+      r#"import Head from "next/head";
+
+const {name} = () => {{
+  return (
+    <div>
+      <Head>
+        <title>{name}</title>
+      </Head>
+    </div>
+  );
+}};
+
+export default {name};"#
+    ),
+  )]
+}
+
+fn app_page_files(src: &str, target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let ext = target.options.lang.jsx_ext();
+  let route = to_kebab_case(&target.name);
+  let name = to_pascal_case(&target.name);
+  let (metadata_import, metadata_type) = match target.options.lang {
+    Lang::Ts => ("import type { Metadata } from \"next\";\n\n", ": Metadata"),
+    Lang::Js => ("", ""),
+  };
+
+  vec![GeneratedFile::new(
+    format!("{src}app/{route}/page.{ext}"),
+    format!(
+      r#"{metadata_import}export const metadata{metadata_type} = {{
+  title: "{name}",
+}};
+
+const {name} = () => {{
+  return <div></div>;
+}};
+
+export default {name};"#
+    ),
+  )]
+}
+
+fn api_route_files(src: &str, target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let ext = target.options.lang.script_ext();
+  let route = to_kebab_case(&target.name);
+  let content = match target.options.lang {
+    Lang::Ts => {
+      r#"import type { NextApiRequest, NextApiResponse } from "next";
+
+const handler = (req: NextApiRequest, res: NextApiResponse) => {
+  res.status(200).json({});
+};
+
+export default handler;"#
+    }
+    Lang::Js => {
+      r#"const handler = (req, res) => {
+  res.status(200).json({});
+};
+
+export default handler;"#
+    }
+  };
+
+  vec![GeneratedFile::new(
+    format!("{src}pages/api/{route}.{ext}"),
+    content.to_owned(),
+  )]
+}
+
+fn route_handler_files(src: &str, target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let ext = target.options.lang.script_ext();
+  let route = to_kebab_case(&target.name);
+
+  vec![GeneratedFile::new(
+    format!("{src}app/api/{route}/route.{ext}"),
+    r#"export const GET = () => {
+  return Response.json({});
+};"#
+      .to_owned(),
+  )]
+}
@@ -0,0 +1,204 @@
+use super::{to_hook_name, GenerateTarget, GeneratedFile, NEST_ONLY};
+use crate::cli::{GenerateKind, Lang};
+use crate::utils::to_pascal_case;
+
+pub fn generate_react(target: &GenerateTarget) -> anyhow::Result<Vec<GeneratedFile>> {
+  match target.kind {
+    GenerateKind::Component => Ok(component_files("src/components", target, true)),
+    GenerateKind::Page => Ok(component_files("src/pages", target, true)),
+    GenerateKind::Layout => Ok(layout_files("src/layouts", target)),
+    GenerateKind::Hook => Ok(hook_files("src/hooks", target, true)),
+    GenerateKind::ApiRoute => anyhow::bail!("React apps have no API routes"),
+    GenerateKind::Module | GenerateKind::Controller => anyhow::bail!(NEST_ONLY),
+  }
+}
+
+pub fn component_files(dir: &str, target: &GenerateTarget, vitest: bool) -> Vec<GeneratedFile> {
+  let lang = &target.options.lang;
+  let ext = lang.jsx_ext();
+  let name = to_pascal_case(&target.name);
+  let (styles_import, class_name) = styles_import(&name, target.css_module);
+  let component = match lang {
+    Lang::Ts => format!(
+      r#"import type {{ FC }} from "react";
+{styles_import}
+const {name}: FC = () => {{"#
+    ),
+    Lang::Js => format!(r#"{}const {name} = () => {{"#, js_preamble(&styles_import)),
+  };
+
+  let mut files = vec![GeneratedFile::new(
+    format!("{dir}/{name}.{ext}"),
+    format!(
+      r#"{component}
+  return <div{class_name}></div>;
+}};
+
+export default {name};"#
+    ),
+  )];
+
+  if target.css_module {
+    files.push(css_module_file(dir, &name));
+  }
+
+  if target.test {
+    files.push(GeneratedFile::new(
+      format!("{dir}/{name}.test.{ext}"),
+      format!(
+        r#"{}import {{ render }} from "@testing-library/react";
+import {name} from "./{name}";
+
+describe("{name}", () => {{
+  it("renders", () => {{
+    const {{ container }} = render(<{name} />);
+    expect(container.firstChild).not.toBeNull();
+  }});
+}});"#,
+        vitest_import(vitest)
+      ),
+    ));
+  }
+
+  if target.story {
+    let content = match lang {
+      Lang::Ts => format!(
+        r#"import type {{ Meta, StoryObj }} from "@storybook/react";
+import {name} from "./{name}";
+
+const meta: Meta<typeof {name}> = {{
+  component: {name},
+}};
+
+export default meta;
+
+export const Default: StoryObj<typeof {name}> = {{}};"#
+      ),
+      Lang::Js => format!(
+        r#"import {name} from "./{name}";
+
+const meta = {{
+  component: {name},
+}};
+
+export default meta;
+
+export const Default = {{}};"#
+      ),
+    };
+
+    files.push(GeneratedFile::new(
+      format!("{dir}/{name}.stories.{ext}"),
+      content,
+    ));
+  }
+
+  files
+}
+
+pub fn layout_files(dir: &str, target: &GenerateTarget) -> Vec<GeneratedFile> {
+  let lang = &target.options.lang;
+  let name = to_pascal_case(&target.name);
+  let (styles_import, class_name) = styles_import(&name, target.css_module);
+  let content = match lang {
+    Lang::Ts => format!(
+      r#"import type {{ FC, ReactNode }} from "react";
+{styles_import}
+interface IProps {{
+  children: ReactNode;
+}}
+
+const {name}: FC<IProps> = ({{ children }}) => <main{class_name}>{{children}}</main>;
+
+export default {name};"#
+    ),
+    Lang::Js => format!(
+      r#"{}const {name} = ({{ children }}) => <main{class_name}>{{children}}</main>;
+
+export default {name};"#,
+      js_preamble(&styles_import)
+    ),
+  };
+
+  let mut files = vec![GeneratedFile::new(
+    format!("{dir}/{name}.{}", lang.jsx_ext()),
+    content,
+  )];
+
+  if target.css_module {
+    files.push(css_module_file(dir, &name));
+  }
+
+  files
+}
+
+pub fn hook_files(dir: &str, target: &GenerateTarget, vitest: bool) -> Vec<GeneratedFile> {
+  let ext = target.options.lang.script_ext();
+  let name = to_hook_name(&target.name);
+
+  let mut files = vec![GeneratedFile::new(
+    format!("{dir}/{name}.{ext}"),
+    format!(
+      r#"import {{ useEffect }} from "react";
+
+const {name} = () => {{
+  useEffect(() => {{}}, []);
+}};
+
+export default {name};"#
+    ),
+  )];
+
+  if target.test {
+    files.push(GeneratedFile::new(
+      format!("{dir}/{name}.test.{ext}"),
+      format!(
+        r#"{}import {{ renderHook }} from "@testing-library/react";
+import {name} from "./{name}";
+
+describe("{name}", () => {{
+  it("runs", () => {{
+    const {{ result }} = renderHook(() => {name}());
+    expect(result.current).toBeUndefined();
+  }});
+}});"#,
+        vitest_import(vitest)
+      ),
+    ));
+  }
+
+  files
+}
+
+/// Returns the CSS module import line and the matching `className` prop.
+fn styles_import(name: &str, css_module: bool) -> (String, &'static str) {
+  if css_module {
+    (
+      format!("import styles from \"./{name}.module.css\";\n"),
+      " className={styles.root}",
+    )
+  } else {
+    (String::new(), "")
+  }
+}
+
+/// Separates the imports from the code in files without a type import line.
+fn js_preamble(styles_import: &str) -> String {
+  if styles_import.is_empty() {
+    String::new()
+  } else {
+    format!("{styles_import}\n")
+  }
+}
+
+fn css_module_file(dir: &str, name: &str) -> GeneratedFile {
+  GeneratedFile::new(format!("{dir}/{name}.module.css"), ".root {\n}".to_owned())
+}
+
+fn vitest_import(vitest: bool) -> &'static str {
+  if vitest {
+    "import { describe, expect, it } from \"vitest\";\n"
+  } else {
+    ""
+  }
+}
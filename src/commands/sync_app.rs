@@ -0,0 +1,329 @@
+use crate::cli::{AppType, SyncOptions};
+use crate::commands::create_app::{
+  create_app_structure, find_workspace, link_tsconfig, AppWithPath,
+};
+use crate::configs::AppManifest;
+use crate::utils::list_files;
+
+#[derive(Debug, PartialEq, Eq)]
+enum SyncOutcome {
+  Unchanged,
+  Created,
+  Updated,
+  Merged,
+  Conflicted,
+  SkippedDeleted,
+  SkippedBinary,
+}
+
+pub fn sync_app(options: SyncOptions) -> anyhow::Result<()> {
+  let SyncOptions { app_path, rej } = options;
+
+  let app_path = app_path.canonicalize()?;
+  let mut manifest = AppManifest::load(&app_path)?;
+  // Removed on drop, so a failed merge doesn't leave the render behind.
+  let render_dir = tempfile::Builder::new().prefix("deez-sync-").tempdir()?;
+  let render_path = render_dir.path().to_path_buf();
+
+  create_app_structure(AppWithPath {
+    app_type: manifest.app_type.clone(),
+    app_name: manifest.app_name.clone(),
+    app_path: render_path.clone(),
+    options: manifest.options.clone(),
+  })?;
+  // `deez create --in-workspace` links workspace members to the shared tsconfig.
+  let in_workspace = !matches!(manifest.app_type, AppType::Workspace)
+    && app_path
+      .parent()
+      .is_some_and(|parent| find_workspace(parent).is_ok());
+  if in_workspace {
+    link_tsconfig(&render_path)?;
+  }
+
+  let conflicts = sync_files(&app_path, &render_path, rej)?;
+
+  manifest.deez_version = env!("CARGO_PKG_VERSION").to_owned();
+  manifest.snapshot(&render_path, &app_path)?;
+  manifest.store(&app_path)?;
+
+  if conflicts > 0 {
+    let resolution = if rej {
+      "see the .rej files"
+    } else {
+      "resolve the conflict markers"
+    };
+    println!("{conflicts} file(s) had conflicts, {resolution}");
+  }
+
+  Ok(())
+}
+
+/// Merges every rendered file into the app and returns how many conflicted.
+/// Files the template no longer renders are left to the app.
+fn sync_files(
+  app_path: &std::path::Path,
+  render_path: &std::path::Path,
+  rej: bool,
+) -> anyhow::Result<usize> {
+  let mut conflicts = 0;
+
+  for file in list_files(render_path)? {
+    let outcome = sync_file(&file, app_path, render_path, rej)?;
+    let file = file.display();
+
+    match outcome {
+      SyncOutcome::Unchanged => {}
+      SyncOutcome::Created => println!("created    {file}"),
+      SyncOutcome::Updated => println!("updated    {file}"),
+      SyncOutcome::Merged => println!("merged     {file}"),
+      SyncOutcome::SkippedDeleted => println!("skipped    {file} (deleted locally)"),
+      SyncOutcome::SkippedBinary => println!("skipped    {file} (binary, changed locally)"),
+      SyncOutcome::Conflicted => {
+        conflicts += 1;
+        println!("conflict   {file}");
+      }
+    }
+  }
+
+  Ok(conflicts)
+}
+
+fn sync_file(
+  file: &std::path::Path,
+  app_path: &std::path::Path,
+  render_path: &std::path::Path,
+  rej: bool,
+) -> anyhow::Result<SyncOutcome> {
+  let theirs = std::fs::read(render_path.join(file))?;
+  let base = std::fs::read(AppManifest::base_path(app_path).join(file)).ok();
+  let target = app_path.join(file);
+  let ours = std::fs::read(&target).ok();
+
+  if base.as_deref() == Some(theirs.as_slice()) {
+    return Ok(SyncOutcome::Unchanged);
+  }
+
+  let ours = match (ours, &base) {
+    (Some(ours), _) => ours,
+    (None, Some(_)) => return Ok(SyncOutcome::SkippedDeleted),
+    (None, None) => {
+      if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
+      }
+      std::fs::write(&target, theirs)?;
+      return Ok(SyncOutcome::Created);
+    }
+  };
+
+  if ours == theirs {
+    return Ok(SyncOutcome::Unchanged);
+  }
+
+  let base = base.unwrap_or_default();
+
+  if ours == base {
+    std::fs::write(&target, theirs)?;
+    return Ok(SyncOutcome::Updated);
+  }
+
+  // Binary files like icons can't be merged, so local changes win.
+  let (Ok(base), Ok(ours), Ok(theirs)) = (
+    String::from_utf8(base),
+    String::from_utf8(ours),
+    String::from_utf8(theirs),
+  ) else {
+    return Ok(SyncOutcome::SkippedBinary);
+  };
+
+  match diffy::merge(&base, &ours, &theirs) {
+    Ok(merged) => {
+      std::fs::write(&target, merged)?;
+      Ok(SyncOutcome::Merged)
+    }
+    Err(_) if rej => {
+      let mut rej_path = target.into_os_string();
+      rej_path.push(".rej");
+      std::fs::write(rej_path, diffy::create_patch(&base, &theirs).to_string())?;
+      Ok(SyncOutcome::Conflicted)
+    }
+    Err(conflicted) => {
+      std::fs::write(&target, conflicted)?;
+      Ok(SyncOutcome::Conflicted)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// An app with `base` as its snapshot and `ours` as its files, and a
+  /// template render with `theirs`.
+  struct Fixture {
+    app: tempfile::TempDir,
+    render: tempfile::TempDir,
+  }
+
+  impl Fixture {
+    fn new(base: &[(&str, &str)], ours: &[(&str, &str)], theirs: &[(&str, &str)]) -> Self {
+      let fixture = Self {
+        app: tempfile::tempdir().unwrap(),
+        render: tempfile::tempdir().unwrap(),
+      };
+
+      write(&AppManifest::base_path(fixture.app.path()), base);
+      write(fixture.app.path(), ours);
+      write(fixture.render.path(), theirs);
+      fixture
+    }
+
+    fn sync(&self, file: &str, rej: bool) -> SyncOutcome {
+      let file = std::path::Path::new(file);
+
+      sync_file(file, self.app.path(), self.render.path(), rej).unwrap()
+    }
+
+    fn read(&self, file: &str) -> Option<String> {
+      std::fs::read_to_string(self.app.path().join(file)).ok()
+    }
+  }
+
+  fn write(dir: &std::path::Path, files: &[(&str, &str)]) {
+    for (file, content) in files {
+      let path = dir.join(file);
+
+      std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+      std::fs::write(path, content).unwrap();
+    }
+  }
+
+  #[test]
+  fn merges_local_and_template_changes_to_different_lines() {
+    let fixture = Fixture::new(
+      &[("a.txt", "one\ntwo\nthree\n")],
+      &[("a.txt", "ONE\ntwo\nthree\n")],
+      &[("a.txt", "one\ntwo\nTHREE\n")],
+    );
+
+    assert_eq!(fixture.sync("a.txt", false), SyncOutcome::Merged);
+    assert_eq!(fixture.read("a.txt").unwrap(), "ONE\ntwo\nTHREE\n");
+  }
+
+  #[test]
+  fn takes_the_template_when_the_file_is_untouched() {
+    let fixture = Fixture::new(
+      &[("a.txt", "one\n")],
+      &[("a.txt", "one\n")],
+      &[("a.txt", "two\n")],
+    );
+
+    assert_eq!(fixture.sync("a.txt", false), SyncOutcome::Updated);
+    assert_eq!(fixture.read("a.txt").unwrap(), "two\n");
+  }
+
+  #[test]
+  fn keeps_local_changes_when_the_template_is_unchanged() {
+    let fixture = Fixture::new(
+      &[("a.txt", "one\n")],
+      &[("a.txt", "local\n")],
+      &[("a.txt", "one\n")],
+    );
+
+    assert_eq!(fixture.sync("a.txt", false), SyncOutcome::Unchanged);
+    assert_eq!(fixture.read("a.txt").unwrap(), "local\n");
+  }
+
+  #[test]
+  fn writes_conflict_markers_for_overlapping_changes() {
+    let fixture = Fixture::new(
+      &[("a.txt", "one\n")],
+      &[("a.txt", "local\n")],
+      &[("a.txt", "template\n")],
+    );
+
+    assert_eq!(fixture.sync("a.txt", false), SyncOutcome::Conflicted);
+
+    let merged = fixture.read("a.txt").unwrap();
+    assert!(merged.contains("<<<<<<<"));
+    assert!(merged.contains("local\n"));
+    assert!(merged.contains("template\n"));
+    assert!(fixture.read("a.txt.rej").is_none());
+  }
+
+  #[test]
+  fn writes_rej_files_for_overlapping_changes() {
+    let fixture = Fixture::new(
+      &[("a.txt", "one\n")],
+      &[("a.txt", "local\n")],
+      &[("a.txt", "template\n")],
+    );
+
+    assert_eq!(fixture.sync("a.txt", true), SyncOutcome::Conflicted);
+    assert_eq!(fixture.read("a.txt").unwrap(), "local\n");
+
+    let rej = fixture.read("a.txt.rej").unwrap();
+    assert!(rej.contains("-one"));
+    assert!(rej.contains("+template"));
+  }
+
+  #[test]
+  fn creates_files_new_to_the_template() {
+    let fixture = Fixture::new(&[], &[], &[("src/new.txt", "new\n")]);
+
+    assert_eq!(fixture.sync("src/new.txt", false), SyncOutcome::Created);
+    assert_eq!(fixture.read("src/new.txt").unwrap(), "new\n");
+  }
+
+  #[test]
+  fn skips_files_deleted_locally() {
+    let fixture = Fixture::new(&[("a.txt", "one\n")], &[], &[("a.txt", "two\n")]);
+
+    assert_eq!(fixture.sync("a.txt", false), SyncOutcome::SkippedDeleted);
+    assert!(fixture.read("a.txt").is_none());
+  }
+
+  #[test]
+  fn keeps_local_changes_to_binary_files() {
+    let fixture = Fixture::new(&[], &[], &[]);
+    let base = AppManifest::base_path(fixture.app.path());
+
+    std::fs::create_dir_all(&base).unwrap();
+    std::fs::write(base.join("icon.png"), [0x89, 0x00]).unwrap();
+    std::fs::write(fixture.app.path().join("icon.png"), [0x89, 0x01]).unwrap();
+    std::fs::write(fixture.render.path().join("icon.png"), [0x89, 0x02]).unwrap();
+
+    assert_eq!(fixture.sync("icon.png", false), SyncOutcome::SkippedBinary);
+    assert_eq!(
+      std::fs::read(fixture.app.path().join("icon.png")).unwrap(),
+      [0x89, 0x01]
+    );
+  }
+
+  #[test]
+  fn leaves_files_deleted_upstream_to_the_app() {
+    let fixture = Fixture::new(
+      &[("a.txt", "one\n"), ("old.txt", "old\n")],
+      &[("a.txt", "one\n"), ("old.txt", "edited\n")],
+      &[("a.txt", "one\n")],
+    );
+
+    let conflicts = sync_files(fixture.app.path(), fixture.render.path(), false).unwrap();
+
+    assert_eq!(conflicts, 0);
+    assert_eq!(fixture.read("old.txt").unwrap(), "edited\n");
+  }
+
+  #[test]
+  fn counts_conflicts_across_files() {
+    let fixture = Fixture::new(
+      &[("a.txt", "one\n"), ("b.txt", "one\n")],
+      &[("a.txt", "local\n"), ("b.txt", "local\n")],
+      &[("a.txt", "template\n"), ("b.txt", "one\n")],
+    );
+
+    let conflicts = sync_files(fixture.app.path(), fixture.render.path(), false).unwrap();
+
+    assert_eq!(conflicts, 1);
+    assert_eq!(fixture.read("b.txt").unwrap(), "local\n");
+  }
+}
@@ -1,2 +1,4 @@
+pub mod app_manifest;
 pub mod create_app_config;
+pub use app_manifest::AppManifest;
 pub use create_app_config::CreateAppConfig;
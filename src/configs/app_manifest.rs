@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::cli::{AppType, TemplateOptions};
+use crate::utils::list_files;
+
+pub const DEEZ_DIR: &str = ".deez";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppManifest {
+  pub deez_version: String,
+  pub app_type: AppType,
+  pub app_name: String,
+  pub files: Vec<String>,
+  #[serde(default)]
+  pub options: TemplateOptions,
+}
+
+impl AppManifest {
+  pub fn new(app_type: AppType, app_name: String, options: TemplateOptions) -> Self {
+    Self {
+      deez_version: env!("CARGO_PKG_VERSION").to_owned(),
+      app_type,
+      app_name,
+      files: Vec::new(),
+      options,
+    }
+  }
+
+  pub fn load(app_path: &std::path::Path) -> anyhow::Result<Self> {
+    use anyhow::Context;
+
+    let manifest_path = app_path.join(DEEZ_DIR).join("manifest.toml");
+    let content = std::fs::read_to_string(&manifest_path)
+      .with_context(|| format!("No deez manifest found at {}", manifest_path.display()))?;
+
+    Ok(toml::from_str(&content)?)
+  }
+
+  pub fn store(&self, app_path: &std::path::Path) -> anyhow::Result<()> {
+    let manifest_path = app_path.join(DEEZ_DIR).join("manifest.toml");
+
+    std::fs::create_dir_all(app_path.join(DEEZ_DIR))?;
+    std::fs::write(manifest_path, toml::to_string(self)?)?;
+
+    Ok(())
+  }
+
+  pub fn base_path(app_path: &std::path::Path) -> std::path::PathBuf {
+    app_path.join(DEEZ_DIR).join("base")
+  }
+
+  /// Copies the rendered template output into the app's base snapshot, which
+  /// is the common ancestor for the next `deez sync`.
+  pub fn snapshot(
+    &mut self,
+    rendered_path: &std::path::Path,
+    app_path: &std::path::Path,
+  ) -> anyhow::Result<()> {
+    let base_path = Self::base_path(app_path);
+
+    if base_path.exists() {
+      std::fs::remove_dir_all(&base_path)?;
+    }
+
+    self.files.clear();
+
+    for file in list_files(rendered_path)? {
+      let target = base_path.join(&file);
+
+      if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
+      }
+      std::fs::copy(rendered_path.join(&file), target)?;
+
+      self.files.push(file.to_string_lossy().into_owned());
+    }
+
+    Ok(())
+  }
+}
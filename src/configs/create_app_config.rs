@@ -25,4 +25,5 @@ impl Default for CreateAppConfig {
 pub enum Integrations {
   Git,
   Tailwind,
+  Eslint,
 }
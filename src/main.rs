@@ -7,7 +7,7 @@ use clap::Parser;
 use cli::Cli;
 use cli::Command;
 
-use commands::create_app;
+use commands::{create_app, generate, sync_app};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -15,6 +15,8 @@ async fn main() -> anyhow::Result<()> {
 
   match &cli.command {
     Command::Create(app) => create_app(app.clone()).await?,
+    Command::Sync(options) => sync_app(options.clone())?,
+    Command::Generate(generator) => generate(generator.clone())?,
   };
 
   Ok(())
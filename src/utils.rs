@@ -1,2 +1,8 @@
 pub mod get_extension_from_filename;
+pub mod list_files;
+pub mod to_kebab_case;
+pub mod to_pascal_case;
 pub use get_extension_from_filename::get_extension_from_filename;
+pub use list_files::list_files;
+pub use to_kebab_case::to_kebab_case;
+pub use to_pascal_case::to_pascal_case;
@@ -0,0 +1,52 @@
+/// Lists every file under `dir` recursively, relative to `dir`, skipping the
+/// `.deez` directory.
+pub fn list_files(dir: &std::path::Path) -> anyhow::Result<Vec<std::path::PathBuf>> {
+  use crate::configs::app_manifest::DEEZ_DIR;
+
+  let mut files = Vec::new();
+  let mut pending = vec![std::path::PathBuf::new()];
+
+  while let Some(relative) = pending.pop() {
+    for entry in std::fs::read_dir(dir.join(&relative))? {
+      let entry = entry?;
+      let relative = relative.join(entry.file_name());
+
+      if entry.file_type()?.is_dir() {
+        if relative.as_os_str() != DEEZ_DIR {
+          pending.push(relative);
+        }
+      } else {
+        files.push(relative);
+      }
+    }
+  }
+
+  files.sort();
+  Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::list_files;
+
+  #[test]
+  fn lists_nested_files_relative_to_the_dir_and_skips_deez() {
+    let dir = tempfile::tempdir().unwrap();
+
+    for file in ["b.txt", "src/a.ts", "src/nested/c.ts", ".deez/base/b.txt"] {
+      let path = dir.path().join(file);
+
+      std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+      std::fs::write(path, "").unwrap();
+    }
+
+    let files = list_files(dir.path()).unwrap();
+
+    assert_eq!(
+      files,
+      ["b.txt", "src/a.ts", "src/nested/c.ts"]
+        .map(std::path::PathBuf::from)
+        .to_vec()
+    );
+  }
+}
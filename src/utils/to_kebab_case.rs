@@ -0,0 +1,5 @@
+use super::to_pascal_case::split_words;
+
+pub fn to_kebab_case(name: &str) -> String {
+  split_words(name).join("-")
+}
@@ -0,0 +1,43 @@
+pub fn to_pascal_case(name: &str) -> String {
+  split_words(name)
+    .iter()
+    .map(|word| {
+      let mut chars = word.chars();
+      match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+      }
+    })
+    .collect()
+}
+
+/// Splits a name on separators and lowercase-to-uppercase boundaries, so
+/// `user-profile`, `user_profile` and `userProfile` give the same words.
+pub fn split_words(name: &str) -> Vec<String> {
+  let mut words = Vec::new();
+  let mut current = String::new();
+  let mut previous_lowercase = false;
+
+  for c in name.chars() {
+    if !c.is_alphanumeric() {
+      if !current.is_empty() {
+        words.push(std::mem::take(&mut current));
+      }
+      previous_lowercase = false;
+      continue;
+    }
+
+    if c.is_uppercase() && previous_lowercase && !current.is_empty() {
+      words.push(std::mem::take(&mut current));
+    }
+
+    previous_lowercase = c.is_lowercase() || c.is_ascii_digit();
+    current.extend(c.to_lowercase());
+  }
+
+  if !current.is_empty() {
+    words.push(current);
+  }
+
+  words
+}